@@ -0,0 +1,40 @@
+#![feature(test)]
+extern crate test;
+
+use did_url::DID;
+
+#[bench]
+fn fragment(bench: &mut test::Bencher) {
+  let did: DID = DID::parse("did:example:bench/path/to/that?foo=a&bar=b&baz=c").unwrap();
+
+  bench.iter(|| did.join(test::black_box("#my-frag")).unwrap());
+}
+
+#[bench]
+fn query(bench: &mut test::Bencher) {
+  let did: DID = DID::parse("did:example:bench/path/to/that?foo=a&bar=b&baz=c").unwrap();
+
+  bench.iter(|| did.join(test::black_box("?versionId=2")).unwrap());
+}
+
+#[bench]
+fn relative_path(bench: &mut test::Bencher) {
+  let did: DID = DID::parse("did:example:bench/path/to/that?foo=a&bar=b&baz=c").unwrap();
+
+  bench.iter(|| {
+    did
+      .join(test::black_box("../other/./that?q#my-frag"))
+      .unwrap()
+  });
+}
+
+#[bench]
+fn absolute(bench: &mut test::Bencher) {
+  let did: DID = DID::parse("did:example:bench/path/to/that?foo=a&bar=b&baz=c").unwrap();
+
+  bench.iter(|| {
+    did
+      .join(test::black_box("did:other:bench/path#my-frag"))
+      .unwrap()
+  });
+}
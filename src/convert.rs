@@ -0,0 +1,249 @@
+//! Conversions between [`DID`] and types from other URI crates.
+
+#[cfg(any(feature = "url", feature = "http"))]
+use core::convert::TryFrom;
+
+#[cfg(any(feature = "url", feature = "http"))]
+use crate::did::DID;
+#[cfg(any(feature = "url", feature = "http"))]
+use crate::error::Error;
+#[cfg(feature = "http")]
+use crate::relative::RelativeDid;
+
+// =============================================================================
+// url
+// =============================================================================
+
+#[cfg(feature = "url")]
+impl From<&DID> for ::url::Url {
+  fn from(other: &DID) -> Self {
+    // Every valid DID URL is a valid URL with an opaque path so the
+    // serialization is preserved as-is.
+    ::url::Url::parse(other.as_str()).expect("DID is a valid URL")
+  }
+}
+
+#[cfg(feature = "url")]
+impl From<DID> for ::url::Url {
+  fn from(other: DID) -> Self {
+    Self::from(&other)
+  }
+}
+
+#[cfg(feature = "url")]
+impl TryFrom<&::url::Url> for DID {
+  type Error = Error;
+
+  fn try_from(other: &::url::Url) -> Result<Self, Self::Error> {
+    if other.scheme() != DID::SCHEME {
+      return Err(Error::InvalidScheme);
+    }
+
+    DID::parse(other.as_str())
+  }
+}
+
+#[cfg(feature = "url")]
+impl TryFrom<::url::Url> for DID {
+  type Error = Error;
+
+  fn try_from(other: ::url::Url) -> Result<Self, Self::Error> {
+    Self::try_from(&other)
+  }
+}
+
+// =============================================================================
+// http
+//
+// Note: `http::Uri` requires an authority alongside a scheme and has no
+// fragment so it cannot represent an absolute DID URL. DIDs are carried in
+// header values (eg. `Link`) instead. Relative references with an absolute
+// path and no fragment are valid origin-form URIs (eg. path params).
+// =============================================================================
+
+#[cfg(feature = "http")]
+impl From<&DID> for ::http::HeaderValue {
+  fn from(other: &DID) -> Self {
+    // A DID URL only contains visible ASCII characters.
+    ::http::HeaderValue::from_str(other.as_str()).expect("DID is a valid header value")
+  }
+}
+
+#[cfg(feature = "http")]
+impl From<DID> for ::http::HeaderValue {
+  fn from(other: DID) -> Self {
+    Self::from(&other)
+  }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<&::http::HeaderValue> for DID {
+  type Error = Error;
+
+  fn try_from(other: &::http::HeaderValue) -> Result<Self, Self::Error> {
+    DID::parse(alloc::string::String::from_utf8_lossy(other.as_bytes()))
+  }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<&RelativeDid> for ::http::Uri {
+  type Error = Error;
+
+  fn try_from(other: &RelativeDid) -> Result<Self, Self::Error> {
+    // `http::Uri` silently drops the fragment.
+    if other.fragment().is_some() {
+      return Err(Error::InvalidFragment);
+    }
+
+    if !other.path().starts_with('/') {
+      return Err(Error::InvalidPath);
+    }
+
+    ::http::Uri::try_from(other.as_str()).map_err(|_| Error::InvalidPath)
+  }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<&::http::Uri> for RelativeDid {
+  type Error = Error;
+
+  fn try_from(other: &::http::Uri) -> Result<Self, Self::Error> {
+    if other.scheme().is_some() {
+      return Err(Error::InvalidScheme);
+    }
+
+    if other.authority().is_some() {
+      return Err(Error::InvalidAuthority);
+    }
+
+    RelativeDid::parse(other.path_and_query().map_or("", |data| data.as_str()))
+  }
+}
+
+// =============================================================================
+// iri-string
+// =============================================================================
+
+#[cfg(feature = "iri-string")]
+mod iri {
+  use core::convert::TryFrom;
+  use iri_string::types::IriReferenceStr;
+  use iri_string::types::IriRelativeStr;
+  use iri_string::types::IriStr;
+  use iri_string::types::IriString;
+  use iri_string::types::UriReferenceStr;
+  use iri_string::types::UriRelativeStr;
+  use iri_string::types::UriStr;
+  use iri_string::types::UriString;
+
+  use crate::did::DID;
+  use crate::error::Error;
+  use crate::relative::RelativeDid;
+
+  // Every valid DID URL is a valid URI (and therefore IRI) so the conversions
+  // from `DID` are infallible.
+
+  impl<'a> From<&'a DID> for &'a UriStr {
+    fn from(other: &'a DID) -> Self {
+      UriStr::new(other.as_str()).expect("DID is a valid URI")
+    }
+  }
+
+  impl<'a> From<&'a DID> for &'a UriReferenceStr {
+    fn from(other: &'a DID) -> Self {
+      UriReferenceStr::new(other.as_str()).expect("DID is a valid URI reference")
+    }
+  }
+
+  impl From<&DID> for UriString {
+    fn from(other: &DID) -> Self {
+      <&UriStr>::from(other).into()
+    }
+  }
+
+  impl From<&DID> for IriString {
+    fn from(other: &DID) -> Self {
+      UriString::from(other).into()
+    }
+  }
+
+  impl TryFrom<&UriStr> for DID {
+    type Error = Error;
+
+    fn try_from(other: &UriStr) -> Result<Self, Self::Error> {
+      parse(other.scheme_str(), other.as_str())
+    }
+  }
+
+  impl TryFrom<&IriStr> for DID {
+    type Error = Error;
+
+    fn try_from(other: &IriStr) -> Result<Self, Self::Error> {
+      parse(other.scheme_str(), other.as_str())
+    }
+  }
+
+  /// Relative references are rejected with [`Error::InvalidScheme`]; convert
+  /// them to a [`RelativeDid`] or resolve them with [`DID::join`] instead.
+  impl TryFrom<&UriReferenceStr> for DID {
+    type Error = Error;
+
+    fn try_from(other: &UriReferenceStr) -> Result<Self, Self::Error> {
+      parse(other.scheme_str().unwrap_or_default(), other.as_str())
+    }
+  }
+
+  /// Relative references are rejected with [`Error::InvalidScheme`]; convert
+  /// them to a [`RelativeDid`] or resolve them with [`DID::join`] instead.
+  impl TryFrom<&IriReferenceStr> for DID {
+    type Error = Error;
+
+    fn try_from(other: &IriReferenceStr) -> Result<Self, Self::Error> {
+      parse(other.scheme_str().unwrap_or_default(), other.as_str())
+    }
+  }
+
+  impl<'a> TryFrom<&'a RelativeDid> for &'a UriRelativeStr {
+    type Error = Error;
+
+    fn try_from(other: &'a RelativeDid) -> Result<Self, Self::Error> {
+      UriRelativeStr::new(other.as_str()).map_err(|_| Error::InvalidPath)
+    }
+  }
+
+  impl TryFrom<&UriRelativeStr> for RelativeDid {
+    type Error = Error;
+
+    fn try_from(other: &UriRelativeStr) -> Result<Self, Self::Error> {
+      RelativeDid::parse(other.as_str())
+    }
+  }
+
+  impl TryFrom<&IriRelativeStr> for RelativeDid {
+    type Error = Error;
+
+    fn try_from(other: &IriRelativeStr) -> Result<Self, Self::Error> {
+      RelativeDid::parse(other.as_str())
+    }
+  }
+
+  /// Absolute references are rejected with [`Error::InvalidScheme`].
+  impl TryFrom<&UriReferenceStr> for RelativeDid {
+    type Error = Error;
+
+    fn try_from(other: &UriReferenceStr) -> Result<Self, Self::Error> {
+      match other.to_iri() {
+        Ok(_) => Err(Error::InvalidScheme),
+        Err(relative) => Self::try_from(relative),
+      }
+    }
+  }
+
+  fn parse(scheme: &str, data: &str) -> Result<DID, Error> {
+    if scheme != DID::SCHEME {
+      return Err(Error::InvalidScheme);
+    }
+
+    DID::parse(data)
+  }
+}
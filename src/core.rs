@@ -4,11 +4,16 @@ use alloc::string::String;
 use core::ops::Range;
 use core::ops::RangeFrom;
 use core::ops::RangeTo;
+use percent_encoding::AsciiSet;
+use percent_encoding::CONTROLS;
+use percent_encoding::NON_ALPHANUMERIC;
 
 use crate::did::DID;
 use crate::error::Error;
 use crate::error::Result;
 use crate::input::Input;
+use crate::query::QueryPairs;
+use crate::query::RawQueryPairs;
 
 #[derive(Clone, Debug)]
 pub struct Core {
@@ -63,7 +68,15 @@ impl Core {
       .map(|fragment| self.slice(data, fragment + 1..))
   }
 
-  pub(crate) fn query_pairs<'a>(&self, data: &'a str) -> form_urlencoded::Parse<'a> {
+  pub(crate) fn query_pairs<'a>(&self, data: &'a str) -> QueryPairs<'a> {
+    QueryPairs::new(self.query(data).unwrap_or_default())
+  }
+
+  pub(crate) fn raw_query_pairs<'a>(&self, data: &'a str) -> RawQueryPairs<'a> {
+    RawQueryPairs::new(self.query(data).unwrap_or_default())
+  }
+
+  pub(crate) fn form_query_pairs<'a>(&self, data: &'a str) -> form_urlencoded::Parse<'a> {
     form_urlencoded::parse(self.query(data).unwrap_or_default().as_bytes())
   }
 
@@ -89,10 +102,7 @@ impl Core {
   }
 
   pub(crate) fn set_path(&mut self, buffer: &mut String, value: &str) {
-    let end: u32 = self
-      .query
-      .or(self.fragment)
-      .unwrap_or_else(|| buffer.len() as u32);
+    let end: u32 = self.query.or(self.fragment).unwrap_or(buffer.len() as u32);
 
     let int: Int = Int::new(end, self.path + value.len() as u32);
 
@@ -111,7 +121,7 @@ impl Core {
         self.query = Some(fragment);
         self.fragment = Some(fragment + value.len() as u32 + 1);
 
-        buffer.insert_str(fragment as usize, "?");
+        buffer.insert(fragment as usize, '?');
         buffer.insert_str(fragment as usize + 1, value);
       }
       (Some(query), Some(fragment), Some(value)) => {
@@ -154,6 +164,50 @@ impl Core {
     }
   }
 
+  pub(crate) fn validate_method(value: &str) -> Result<()> {
+    if value.is_empty() || !value.chars().all(char_method) {
+      return Err(Error::InvalidMethodName);
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn validate_method_id(value: &str) -> Result<()> {
+    if value.is_empty() || !all_encoded(value, char_method_id) {
+      return Err(Error::InvalidMethodId);
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn validate_path(value: &str) -> Result<()> {
+    if !value.is_empty() && !value.starts_with('/') {
+      return Err(Error::InvalidPath);
+    }
+
+    if !all_encoded(value, char_path) {
+      return Err(Error::InvalidPath);
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn validate_query(value: &str) -> Result<()> {
+    if !all_encoded(value, char_query) {
+      return Err(Error::InvalidQuery);
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn validate_fragment(value: &str) -> Result<()> {
+    if !all_encoded(value, char_fragment) {
+      return Err(Error::InvalidFragment);
+    }
+
+    Ok(())
+  }
+
   fn slice<'a>(&self, data: &'a str, range: impl SliceExt) -> &'a str {
     range.slice(data)
   }
@@ -164,7 +218,7 @@ impl Core {
   ///   method-name        = 1*method-char
   ///   method-char        = %x61-7A / DIGIT
   ///   method-specific-id = *( *idchar ":" ) 1*idchar
-  ///   idchar             = ALPHA / DIGIT / "." / "-" / "_"
+  ///   idchar             = ALPHA / DIGIT / "." / "-" / "_" / pct-encoded
   ///
   ///   did-url            = did path-abempty [ "?" query ] [ "#" fragment ]
   ///
@@ -180,8 +234,9 @@ impl Core {
   ///   fragment           = *( pchar / "/" / "?" )
   ///
   pub(crate) fn parse(data: impl AsRef<str>) -> Result<Self> {
+    let data: &str = Input::trim(data.as_ref());
     let mut this: Self = Self::new();
-    let mut input: Input = Input::new(data.as_ref());
+    let mut input: Input = Input::new(data);
 
     this.parse_scheme(&mut input)?;
     this.parse_method(&mut input)?;
@@ -190,11 +245,11 @@ impl Core {
     this.parse_query(&mut input)?;
     this.parse_fragment(&mut input)?;
 
-    if this.method(data.as_ref()).is_empty() {
+    if this.method(data).is_empty() {
       return Err(Error::InvalidMethodName);
     }
 
-    if this.method_id(data.as_ref()).is_empty() {
+    if this.method_id(data).is_empty() {
       return Err(Error::InvalidMethodId);
     }
 
@@ -202,13 +257,27 @@ impl Core {
   }
 
   pub(crate) fn parse_relative(data: impl AsRef<str>) -> Result<Self> {
+    let data: &str = data.as_ref();
     let mut this: Self = Self::new();
-    let mut input: Input = Input::new(data.as_ref());
+    let mut input: Input = Input::new(data);
 
     this.parse_path(&mut input)?;
     this.parse_query(&mut input)?;
     this.parse_fragment(&mut input)?;
 
+    // The first segment of a relative-path reference must not contain a
+    // colon, or it would be read as a scheme.
+    //
+    // [More Info](https://tools.ietf.org/html/rfc3986#section-4.2)
+    if this
+      .path(data)
+      .split('/')
+      .next()
+      .is_some_and(|segment| segment.contains(':'))
+    {
+      return Err(Error::InvalidPath);
+    }
+
     Ok(this)
   }
 
@@ -258,6 +327,7 @@ impl Core {
     loop {
       match input.peek() {
         Some('/') | Some('?') | Some('#') | None => break,
+        Some('%') if input.pct_encoded() => continue,
         Some(ch) if char_method_id(ch) => {}
         _ => return Err(Error::InvalidMethodId),
       }
@@ -278,6 +348,7 @@ impl Core {
     loop {
       match input.peek() {
         Some('?') | Some('#') | None => break,
+        Some('%') if input.pct_encoded() => continue,
         Some(ch) if char_path(ch) => {}
         _ => return Err(Error::InvalidPath),
       }
@@ -304,6 +375,7 @@ impl Core {
     loop {
       match input.peek() {
         Some('#') | None => break,
+        Some('%') if input.pct_encoded() => continue,
         Some(ch) if char_query(ch) => {}
         _ => return Err(Error::InvalidQuery),
       }
@@ -330,6 +402,7 @@ impl Core {
     loop {
       match input.peek() {
         None => break,
+        Some('%') if input.pct_encoded() => continue,
         Some(ch) if char_fragment(ch) => {}
         _ => return Err(Error::InvalidFragment),
       }
@@ -351,14 +424,14 @@ const fn char_method(ch: char) -> bool {
 }
 
 #[inline(always)]
-const fn char_method_id(ch: char) -> bool {
+pub(crate) const fn char_method_id(ch: char) -> bool {
   matches!(ch, '0'..='9' | 'a'..='z' | 'A'..='Z' | '.' | '-' | '_' | ':')
 }
 
 #[inline(always)]
 #[rustfmt::skip]
-const fn char_path(ch: char) -> bool {
-  char_method_id(ch) || matches!(ch, '~' | '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=' | '@' | '/' /* | '%' */)
+pub(crate) const fn char_path(ch: char) -> bool {
+  char_method_id(ch) || matches!(ch, '~' | '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=' | '@' | '/')
 }
 
 #[inline(always)]
@@ -371,6 +444,92 @@ const fn char_fragment(ch: char) -> bool {
   char_path(ch) || ch == '?'
 }
 
+/// Characters not permitted in a single method-specific-id segment (`idchar`).
+pub(crate) const METHOD_ID_ENCODE_SET: &AsciiSet =
+  &NON_ALPHANUMERIC.remove(b'.').remove(b'-').remove(b'_');
+
+/// Characters not permitted in a path (`char_path`).
+#[rustfmt::skip]
+pub(crate) const PATH_ENCODE_SET: &AsciiSet = &CONTROLS
+  .add(b' ').add(b'"').add(b'#').add(b'%').add(b'<').add(b'>').add(b'?')
+  .add(b'[').add(b'\\').add(b']').add(b'^').add(b'`').add(b'{').add(b'|').add(b'}');
+
+/// Characters not permitted in a fragment (`char_fragment`).
+pub(crate) const FRAGMENT_ENCODE_SET: &AsciiSet = &PATH_ENCODE_SET.remove(b'?');
+
+/// Characters not permitted in a query key or value.
+///
+/// This includes the `&` and `=` delimiters; `+` is left as-is since it has no
+/// special meaning in an RFC 3986 query.
+pub(crate) const QUERY_PAIR_ENCODE_SET: &AsciiSet = &FRAGMENT_ENCODE_SET.add(b'&').add(b'=');
+
+/// Returns `true` if every character of `value` satisfies `f` or is part of a
+/// valid percent-encoded octet.
+fn all_encoded(value: &str, f: fn(char) -> bool) -> bool {
+  let mut input: &[u8] = value.as_bytes();
+
+  loop {
+    match input {
+      [] => return true,
+      [b'%', a, b, rest @ ..] if a.is_ascii_hexdigit() && b.is_ascii_hexdigit() => {
+        input = rest;
+      }
+      [byte, rest @ ..] if f(char::from(*byte)) => {
+        input = rest;
+      }
+      _ => return false,
+    }
+  }
+}
+
+/// Appends `value` to `output` with all percent-encoded octets normalized.
+///
+/// Octets encoding an unreserved character permitted by `f` are decoded and
+/// all others have their hexadecimal digits uppercased.
+///
+/// [More Info](https://tools.ietf.org/html/rfc3986#section-6.2.2)
+pub(crate) fn normalize_pct(value: &str, output: &mut String, f: fn(char) -> bool) {
+  let mut input: &[u8] = value.as_bytes();
+
+  loop {
+    match input {
+      [] => break,
+      [b'%', a, b, rest @ ..] if a.is_ascii_hexdigit() && b.is_ascii_hexdigit() => {
+        let byte: u8 = (hex(*a) << 4) | hex(*b);
+
+        if char_unreserved(char::from(byte)) && f(char::from(byte)) {
+          output.push(char::from(byte));
+        } else {
+          output.push('%');
+          output.push(char::from(a.to_ascii_uppercase()));
+          output.push(char::from(b.to_ascii_uppercase()));
+        }
+
+        input = rest;
+      }
+      [byte, rest @ ..] => {
+        output.push(char::from(*byte));
+        input = rest;
+      }
+    }
+  }
+}
+
+#[inline(always)]
+const fn char_unreserved(ch: char) -> bool {
+  matches!(ch, '0'..='9' | 'a'..='z' | 'A'..='Z' | '-' | '.' | '_' | '~')
+}
+
+/// Returns the value of the ASCII hexadecimal digit `byte`.
+#[inline(always)]
+pub(crate) const fn hex(byte: u8) -> u8 {
+  match byte {
+    b'0'..=b'9' => byte - b'0',
+    b'a'..=b'f' => byte - b'a' + 10,
+    _ => byte - b'A' + 10,
+  }
+}
+
 // =============================================================================
 //
 // =============================================================================
@@ -0,0 +1,107 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str;
+use percent_encoding::percent_decode_str;
+
+/// A percent-decoded DID component.
+///
+/// The decoded octets are not guaranteed to be valid UTF-8; use
+/// [`Decoded::into_str`] or [`Decoded::as_bytes`] as appropriate.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Decoded<'a> {
+  bytes: Cow<'a, [u8]>,
+}
+
+impl<'a> Decoded<'a> {
+  pub(crate) fn new(input: &'a str) -> Self {
+    Self {
+      bytes: percent_decode_str(input).into(),
+    }
+  }
+
+  /// Returns the decoded octets.
+  #[inline]
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.bytes
+  }
+
+  /// Returns the decoded string, if it is valid UTF-8.
+  #[inline]
+  pub fn as_str(&self) -> Option<&str> {
+    str::from_utf8(&self.bytes).ok()
+  }
+
+  /// Consumes the [`Decoded`] and returns the decoded octets.
+  #[inline]
+  pub fn into_bytes(self) -> Cow<'a, [u8]> {
+    self.bytes
+  }
+
+  /// Consumes the [`Decoded`] and returns the decoded string.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` with the unchanged octets if they are not valid UTF-8.
+  pub fn into_str(self) -> Result<Cow<'a, str>, Cow<'a, [u8]>> {
+    match self.bytes {
+      Cow::Borrowed(bytes) => str::from_utf8(bytes)
+        .map(Cow::Borrowed)
+        .map_err(|_| Cow::Borrowed(bytes)),
+      Cow::Owned(bytes) => String::from_utf8(bytes)
+        .map(Cow::Owned)
+        .map_err(|error| Cow::Owned(error.into_bytes())),
+    }
+  }
+
+  /// Consumes the [`Decoded`] and returns the decoded string, replacing
+  /// invalid UTF-8 sequences with `U+FFFD`.
+  pub fn into_str_lossy(self) -> Cow<'a, str> {
+    match self.into_str() {
+      Ok(string) => string,
+      Err(bytes) => Cow::Owned(String::from_utf8_lossy(&bytes).into_owned()),
+    }
+  }
+}
+
+impl Debug for Decoded<'_> {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    match self.as_str() {
+      Some(string) => Debug::fmt(string, f),
+      None => Debug::fmt(self.as_bytes(), f),
+    }
+  }
+}
+
+impl PartialEq<str> for Decoded<'_> {
+  fn eq(&self, other: &str) -> bool {
+    self.as_bytes() == other.as_bytes()
+  }
+}
+
+impl PartialEq<&'_ str> for Decoded<'_> {
+  fn eq(&self, other: &&'_ str) -> bool {
+    self == *other
+  }
+}
+
+impl PartialEq<[u8]> for Decoded<'_> {
+  fn eq(&self, other: &[u8]) -> bool {
+    self.as_bytes() == other
+  }
+}
+
+impl<'a> From<Decoded<'a>> for Cow<'a, [u8]> {
+  fn from(other: Decoded<'a>) -> Self {
+    other.into_bytes()
+  }
+}
+
+impl From<Decoded<'_>> for Vec<u8> {
+  fn from(other: Decoded<'_>) -> Self {
+    other.into_bytes().into_owned()
+  }
+}
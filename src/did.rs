@@ -1,4 +1,6 @@
 #[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
 use alloc::string::String;
 #[cfg(feature = "alloc")]
 use alloc::string::ToString as _;
@@ -11,10 +13,25 @@ use core::fmt::Result as FmtResult;
 use core::hash::Hash;
 use core::hash::Hasher;
 use core::str::FromStr;
+use core::str::Split;
+use percent_encoding::utf8_percent_encode;
 
+use crate::core::char_method_id;
+use crate::core::char_path;
+use crate::core::normalize_pct;
 use crate::core::Core;
+use crate::core::FRAGMENT_ENCODE_SET;
+use crate::core::METHOD_ID_ENCODE_SET;
+use crate::core::PATH_ENCODE_SET;
+use crate::core::QUERY_PAIR_ENCODE_SET;
+use crate::decoded::Decoded;
 use crate::error::Error;
 use crate::error::Result;
+use crate::input::Input;
+use crate::query::QueryPairs;
+use crate::query::RawQueryPairs;
+use crate::registry::MethodError;
+use crate::registry::MethodRegistry;
 
 #[derive(Clone, Copy)]
 pub struct Inspect<'a>(&'a DID);
@@ -52,15 +69,40 @@ impl DID {
   ///
   /// Returns `Err` if any DID segments are invalid.
   pub fn parse(input: impl AsRef<str>) -> Result<Self> {
+    // Leading and trailing control characters and spaces are ignored by the
+    // parser, so they are not kept in the serialization.
+    let data: &str = Input::trim(input.as_ref());
+
     Ok(Self {
-      data: input.as_ref().to_string(),
-      core: Core::parse(input)?,
+      core: Core::parse(data)?,
+      data: data.to_string(),
     })
   }
 
+  /// Parses a [`DID`] from the provided `input` and validates it against the
+  /// method-specific rules of `registry`.
+  ///
+  /// The parsed DID is normalized by its method spec, if any.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if any DID segments are invalid or the DID is rejected by
+  /// `registry`.
+  pub fn parse_with(
+    registry: &MethodRegistry,
+    input: impl AsRef<str>,
+  ) -> Result<Self, MethodError> {
+    let mut this: Self = Self::parse(input)?;
+
+    registry.validate(&this)?;
+    registry.normalize(&mut this);
+
+    Ok(this)
+  }
+
   /// Returns a wrapped `DID` with a more detailed `Debug` implementation.
   #[inline]
-  pub const fn inspect(&self) -> Inspect {
+  pub const fn inspect(&self) -> Inspect<'_> {
     Inspect(self)
   }
 
@@ -69,7 +111,7 @@ impl DID {
   /// This is fast since the serialized value is stored in the [`DID`].
   #[inline]
   pub fn as_str(&self) -> &str {
-    &*self.data
+    &self.data
   }
 
   /// Consumes the [`DID`] and returns the serialization.
@@ -103,6 +145,13 @@ impl DID {
     self.core.method_id(self.as_str())
   }
 
+  /// Returns an iterator over the `:`-separated segments of the [`DID`]
+  /// method-specific ID.
+  #[inline]
+  pub fn method_id_segments(&self) -> Split<'_, char> {
+    self.method_id().split(':')
+  }
+
   /// Returns the [`DID`] path.
   #[inline]
   pub fn path(&self) -> &str {
@@ -121,58 +170,354 @@ impl DID {
     self.core.fragment(self.as_str())
   }
 
+  /// Returns the percent-decoded [`DID`] method-specific ID.
+  #[inline]
+  pub fn method_id_decoded(&self) -> Decoded<'_> {
+    Decoded::new(self.method_id())
+  }
+
+  /// Returns the percent-decoded [`DID`] path.
+  #[inline]
+  pub fn path_decoded(&self) -> Decoded<'_> {
+    Decoded::new(self.path())
+  }
+
+  /// Returns the percent-decoded [`DID`] query, if any.
+  #[inline]
+  pub fn query_decoded(&self) -> Option<Decoded<'_>> {
+    self.query().map(Decoded::new)
+  }
+
+  /// Returns the percent-decoded [`DID`] fragment, if any.
+  #[inline]
+  pub fn fragment_decoded(&self) -> Option<Decoded<'_>> {
+    self.fragment().map(Decoded::new)
+  }
+
   /// Parses the [`DID`] query and returns an iterator of (key, value) pairs.
+  ///
+  /// Keys and values are percent-decoded following RFC 3986; a `+` is *not*
+  /// decoded as a space. See [`DID::form_query_pairs`] for HTML form decoding.
+  ///
+  /// Note: Invalid UTF-8 is replaced with `U+FFFD`; use
+  /// [`DID::raw_query_pairs`] to keep the original octets.
   #[inline]
-  pub fn query_pairs(&self) -> form_urlencoded::Parse {
+  pub fn query_pairs(&self) -> QueryPairs<'_> {
     self.core.query_pairs(self.as_str())
   }
 
+  /// Parses the [`DID`] query and returns an iterator of (key, value) pairs
+  /// as they appear in the query, without percent-decoding.
+  #[inline]
+  pub fn raw_query_pairs(&self) -> RawQueryPairs<'_> {
+    self.core.raw_query_pairs(self.as_str())
+  }
+
+  /// Parses the [`DID`] query as `application/x-www-form-urlencoded` and
+  /// returns an iterator of (key, value) pairs.
+  ///
+  /// Note: This decodes `+` as a space, which corrupts values such as base64.
+  #[inline]
+  pub fn form_query_pairs(&self) -> form_urlencoded::Parse<'_> {
+    self.core.form_query_pairs(self.as_str())
+  }
+
   /// Change the method of the [`DID`].
+  ///
+  /// # Panics
+  ///
+  /// Panics in debug builds if `value` is not a valid method name. See
+  /// [`DID::try_set_method`] for a checked alternative.
   #[inline]
   pub fn set_method(&mut self, value: impl AsRef<str>) {
+    debug_assert!(
+      Core::validate_method(value.as_ref()).is_ok(),
+      "invalid method name"
+    );
+    self.core.set_method(&mut self.data, value.as_ref());
+  }
+
+  /// Change the method of the [`DID`] if `value` is a valid method name.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `value` is not a valid method name.
+  #[inline]
+  pub fn try_set_method(&mut self, value: impl AsRef<str>) -> Result<()> {
+    Core::validate_method(value.as_ref())?;
     self.core.set_method(&mut self.data, value.as_ref());
+    Ok(())
   }
 
   /// Change the method-specific-id of the [`DID`].
+  ///
+  /// # Panics
+  ///
+  /// Panics in debug builds if `value` is not a valid method-specific-id. See
+  /// [`DID::try_set_method_id`] for a checked alternative.
   #[inline]
   pub fn set_method_id(&mut self, value: impl AsRef<str>) {
+    debug_assert!(
+      Core::validate_method_id(value.as_ref()).is_ok(),
+      "invalid method id"
+    );
+    self.core.set_method_id(&mut self.data, value.as_ref());
+  }
+
+  /// Change the method-specific-id of the [`DID`] if `value` is valid.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `value` is not a valid method-specific-id.
+  #[inline]
+  pub fn try_set_method_id(&mut self, value: impl AsRef<str>) -> Result<()> {
+    Core::validate_method_id(value.as_ref())?;
     self.core.set_method_id(&mut self.data, value.as_ref());
+    Ok(())
   }
 
   /// Change the path of the [`DID`].
+  ///
+  /// # Panics
+  ///
+  /// Panics in debug builds if `value` is not a valid path. See
+  /// [`DID::try_set_path`] for a checked alternative.
   #[inline]
   pub fn set_path(&mut self, value: impl AsRef<str>) {
+    debug_assert!(Core::validate_path(value.as_ref()).is_ok(), "invalid path");
     self.core.set_path(&mut self.data, value.as_ref());
   }
 
+  /// Change the path of the [`DID`] if `value` is a valid path.
+  ///
+  /// Non-empty paths must begin with a `/`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `value` is not a valid path.
+  #[inline]
+  pub fn try_set_path(&mut self, value: impl AsRef<str>) -> Result<()> {
+    Core::validate_path(value.as_ref())?;
+    self.core.set_path(&mut self.data, value.as_ref());
+    Ok(())
+  }
+
+  /// Change the path of the [`DID`], percent-encoding any characters not
+  /// permitted in a path.
+  ///
+  /// A leading `/` is added to non-empty values that do not start with one.
+  pub fn set_path_encoded(&mut self, value: impl AsRef<str>) {
+    let value: &str = value.as_ref();
+    let mut path: String = String::with_capacity(value.len() + 1);
+
+    if !value.is_empty() && !value.starts_with('/') {
+      path.push('/');
+    }
+
+    path.extend(utf8_percent_encode(value, PATH_ENCODE_SET));
+
+    self.core.set_path(&mut self.data, &path);
+  }
+
   /// Change the query of the [`DID`].
   ///
   /// No serialization is performed.
+  ///
+  /// # Panics
+  ///
+  /// Panics in debug builds if `value` is not a valid query. See
+  /// [`DID::try_set_query`] for a checked alternative.
   #[inline]
   pub fn set_query(&mut self, value: Option<&str>) {
+    debug_assert!(
+      value.map_or(Ok(()), Core::validate_query).is_ok(),
+      "invalid query"
+    );
     self.core.set_query(&mut self.data, value);
   }
 
+  /// Change the query of the [`DID`] if `value` is a valid query.
+  ///
+  /// No serialization is performed.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `value` is not a valid query.
+  #[inline]
+  pub fn try_set_query(&mut self, value: Option<&str>) -> Result<()> {
+    value.map_or(Ok(()), Core::validate_query)?;
+    self.core.set_query(&mut self.data, value);
+    Ok(())
+  }
+
+  /// Change the query of the [`DID`] to the serialization of `pairs`.
+  ///
+  /// Keys and values are percent-encoded following RFC 3986 so they can be
+  /// recovered with [`DID::query_pairs`]. The query is removed if `pairs` is
+  /// empty.
+  pub fn set_query_pairs<I, K, V>(&mut self, pairs: I)
+  where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+  {
+    let mut query: String = String::new();
+
+    for (key, value) in pairs {
+      if !query.is_empty() {
+        query.push('&');
+      }
+
+      query.extend(utf8_percent_encode(key.as_ref(), QUERY_PAIR_ENCODE_SET));
+      query.push('=');
+      query.extend(utf8_percent_encode(value.as_ref(), QUERY_PAIR_ENCODE_SET));
+    }
+
+    if query.is_empty() {
+      self.core.set_query(&mut self.data, None);
+    } else {
+      self.core.set_query(&mut self.data, Some(&query));
+    }
+  }
+
   /// Change the fragment of the [`DID`].
   ///
   /// No serialization is performed.
+  ///
+  /// # Panics
+  ///
+  /// Panics in debug builds if `value` is not a valid fragment. See
+  /// [`DID::try_set_fragment`] for a checked alternative.
   #[inline]
   pub fn set_fragment(&mut self, value: Option<&str>) {
+    debug_assert!(
+      value.map_or(Ok(()), Core::validate_fragment).is_ok(),
+      "invalid fragment"
+    );
+    self.core.set_fragment(&mut self.data, value);
+  }
+
+  /// Change the fragment of the [`DID`] if `value` is a valid fragment.
+  ///
+  /// No serialization is performed.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `value` is not a valid fragment.
+  #[inline]
+  pub fn try_set_fragment(&mut self, value: Option<&str>) -> Result<()> {
+    value.map_or(Ok(()), Core::validate_fragment)?;
     self.core.set_fragment(&mut self.data, value);
+    Ok(())
+  }
+
+  /// Change the fragment of the [`DID`], percent-encoding any characters not
+  /// permitted in a fragment.
+  pub fn set_fragment_encoded(&mut self, value: Option<&str>) {
+    let value: Option<Cow<str>> =
+      value.map(|value| utf8_percent_encode(value, FRAGMENT_ENCODE_SET).into());
+
+    self.core.set_fragment(&mut self.data, value.as_deref());
+  }
+
+  /// Appends a `:`-separated segment to the method-specific-id of the
+  /// [`DID`], percent-encoding any characters not permitted in an `idchar`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `value` is empty, since the method-specific-id may not
+  /// end with a `:`.
+  pub fn push_method_id_segment_encoded(&mut self, value: impl AsRef<str>) -> Result<()> {
+    if value.as_ref().is_empty() {
+      return Err(Error::InvalidMethodId);
+    }
+
+    let mut method_id: String = self.method_id().to_string();
+
+    method_id.push(':');
+    method_id.extend(utf8_percent_encode(value.as_ref(), METHOD_ID_ENCODE_SET));
+
+    self.core.set_method_id(&mut self.data, &method_id);
+
+    Ok(())
   }
 
-  /// Creates a new [`DID`] by joining `self` with the relative DID `other`.
+  /// Normalizes the [`DID`] using syntax-based normalization.
+  ///
+  /// Hexadecimal digits of percent-encoded octets are uppercased, octets
+  /// encoding unreserved characters are decoded, dot-segments are removed
+  /// from the path, and an empty query or fragment is removed.
+  ///
+  /// [More Info](https://tools.ietf.org/html/rfc3986#section-6.2.2)
+  #[cfg(feature = "alloc")]
+  pub fn normalize(&mut self) {
+    let mut data: String = String::with_capacity(self.data.len());
+    let mut path: String = String::with_capacity(self.path().len());
+
+    data.push_str(DID::SCHEME);
+    data.push(':');
+    data.push_str(self.method());
+    data.push(':');
+    normalize_pct(self.method_id(), &mut data, char_method_id);
+
+    normalize_pct(self.path(), &mut path, char_path);
+    data.push_str(&resolution::remove_dot_segments(&path));
+
+    if let Some(query) = self.query().filter(|query| !query.is_empty()) {
+      data.push('?');
+      normalize_pct(query, &mut data, char_path);
+    }
+
+    if let Some(fragment) = self.fragment().filter(|fragment| !fragment.is_empty()) {
+      data.push('#');
+      normalize_pct(fragment, &mut data, char_path);
+    }
+
+    // Only characters permitted in each component are decoded so the
+    // normalized form is always valid.
+    self.core = Core::parse(&data).expect("normalized DID is valid");
+    self.data = data;
+  }
+
+  /// Creates a new [`DID`] by joining `self` with the DID reference `other`.
+  ///
+  /// `other` may be a relative reference, a network-path reference (eg.
+  /// `//example:123/path`), or an absolute DID URL which replaces the base.
   ///
   /// # Errors
   ///
-  /// Returns `Err` if any base or relative DID segments are invalid.
+  /// Returns `Err` if any base or relative DID segments are invalid, or if
+  /// `other` is an absolute URI with a scheme other than `did`.
   #[cfg(feature = "alloc")]
   pub fn join(&self, other: impl AsRef<str>) -> Result<Self> {
-    let data: &str = other.as_ref();
+    let data: &str = Input::trim(other.as_ref());
+
+    match resolution::reference_scheme(data) {
+      Some(Self::SCHEME) => return resolution::transform_absolute(Self::parse(data)?),
+      Some(_) => return Err(Error::InvalidScheme),
+      None => {}
+    }
+
+    if let Some(authority) = data.strip_prefix("//") {
+      return resolution::transform_absolute(Self::parse([Self::SCHEME, ":", authority].join(""))?);
+    }
+
     let core: Core = Core::parse_relative(data)?;
 
     resolution::transform_references(self, (data, &core))
   }
+
+  /// Returns the shortest relative reference that, when joined with `self`,
+  /// produces `target`.
+  ///
+  /// If the path of `target` cannot be reached with a relative reference (ie.
+  /// it is empty), the serialized `target` is returned as-is.
+  ///
+  /// Returns `None` if `self` and `target` have different authorities.
+  #[cfg(feature = "alloc")]
+  pub fn make_relative(&self, target: &DID) -> Option<String> {
+    resolution::make_relative(self, target)
+  }
 }
 
 impl Hash for DID {
@@ -194,7 +539,7 @@ impl Eq for DID {}
 
 impl PartialOrd for DID {
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    self.as_str().partial_cmp(other.as_str())
+    Some(self.cmp(other))
   }
 }
 
@@ -266,93 +611,228 @@ impl From<DID> for String {
 #[cfg(feature = "alloc")]
 mod resolution {
   use alloc::borrow::Cow;
-  use core::fmt::Display;
-  use core::fmt::Formatter;
-  use core::fmt::Result as FmtResult;
-  use core::str::from_utf8_unchecked;
+  use alloc::string::String;
+  use alloc::string::ToString as _;
+  use alloc::vec::Vec;
 
   use crate::core::Core;
   use crate::did::DID;
   use crate::error::Error;
   use crate::error::Result;
 
-  #[derive(Debug)]
-  #[repr(transparent)]
-  pub struct Path<'a>(Cow<'a, str>);
+  /// Transform References.
+  ///
+  /// Transforms a DID reference into its target DID.
+  ///
+  /// The target components are computed from borrowed slices of `base` and
+  /// `data` and written into a single pre-sized buffer.
+  ///
+  /// [More Info](https://tools.ietf.org/html/rfc3986#section-5.2.2)
+  #[allow(non_snake_case)]
+  pub fn transform_references(base: &DID, (data, core): (&str, &Core)) -> Result<DID> {
+    let P: &str = core.path(data);
+    let Q: Option<&str> = core.query(data);
+    let F: Option<&str> = core.fragment(data);
 
-  impl<'a> Path<'a> {
-    pub const fn new() -> Self {
-      Self(Cow::Borrowed(""))
-    }
+    // The method and method-specific-id (authority) are inherited from the
+    // base.
+    let authority: &str = &base.as_str()[..base.core.path as usize];
 
-    pub fn push(&mut self, value: impl AsRef<[u8]>) {
-      self
-        .0
-        .to_mut()
-        .push_str(unsafe { from_utf8_unchecked(value.as_ref()) });
-    }
+    let mut T: String = String::with_capacity(base.as_str().len() + data.len() + 2);
 
-    pub fn pop(&mut self) {
-      if self.0.is_empty() {
-        return;
-      }
+    T.push_str(authority);
 
-      if let Some(index) = self.0.rfind('/') {
-        self.0.to_mut().replace_range(index.., "");
+    let path: u32 = authority.len() as u32;
+
+    let query: Option<&str> = if P.is_empty() {
+      T.push_str(base.path());
+      Q.or_else(|| base.query())
+    } else {
+      if P.starts_with('/') {
+        T.push_str(P);
+      } else {
+        merge_paths(base, P, &mut T)?;
       }
-    }
+
+      remove_dot_segments_in_place(&mut T, path as usize);
+
+      Q
+    };
+
+    let query: Option<u32> = query.map(|query| {
+      let index: u32 = T.len() as u32;
+
+      T.push('?');
+      T.push_str(query);
+
+      index
+    });
+
+    let fragment: Option<u32> = F.map(|fragment| {
+      let index: u32 = T.len() as u32;
+
+      T.push('#');
+      T.push_str(fragment);
+
+      index
+    });
+
+    let core: Core = Core {
+      method: base.core.method,
+      method_id: base.core.method_id,
+      path,
+      query,
+      fragment,
+    };
+
+    Ok(DID { data: T, core })
   }
 
-  impl<'a> From<Path<'a>> for Cow<'a, str> {
-    fn from(other: Path<'a>) -> Self {
-      other.0
+  /// Returns the scheme of `data` if it is an absolute URI.
+  ///
+  /// [More Info](https://tools.ietf.org/html/rfc3986#section-3.1)
+  pub fn reference_scheme(data: &str) -> Option<&str> {
+    let index: usize = data.find([':', '/', '?', '#'])?;
+    let scheme: &str = &data[..index];
+
+    if !data[index..].starts_with(':') {
+      return None;
+    }
+
+    let mut chars = scheme.chars();
+
+    if !chars.next()?.is_ascii_alphabetic() {
+      return None;
     }
-  }
 
-  impl Display for Path<'_> {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-      Display::fmt(&self.0, f)
+    if !chars.all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.')) {
+      return None;
     }
+
+    Some(scheme)
   }
 
-  /// Transform References.
-  ///
-  /// Transforms a DID reference into its target DID.
+  /// Transforms an absolute or network-path reference into its target DID.
   ///
   /// [More Info](https://tools.ietf.org/html/rfc3986#section-5.2.2)
-  #[allow(non_snake_case)]
-  pub fn transform_references(base: &DID, (data, core): (&str, &Core)) -> Result<DID> {
-    let P: &str = core.path(data);
-    let Q: Option<&str> = core.query(data);
+  pub fn transform_absolute(mut target: DID) -> Result<DID> {
+    let path: String = remove_dot_segments(target.path()).into_owned();
 
-    let mut T: DID = base.clone();
+    target.core.set_path(&mut target.data, &path);
 
-    if P.is_empty() {
-      T.set_path(base.path());
-      T.set_query(Q.or_else(|| base.query()));
-    } else {
-      if P.starts_with('/') {
-        T.set_path(remove_dot_segments(P));
+    Ok(target)
+  }
+
+  /// Make Relative.
+  ///
+  /// Computes the shortest reference that resolves against `base` to `target`.
+  pub fn make_relative(base: &DID, target: &DID) -> Option<String> {
+    if base.authority() != target.authority() {
+      return None;
+    }
+
+    let mut output: String = String::new();
+
+    if base.path() == target.path() {
+      if base.query() == target.query() {
+        // An empty reference resolves to the base without the fragment.
+      } else if let Some(query) = target.query() {
+        output.push('?');
+        output.push_str(query);
       } else {
-        T.set_path(remove_dot_segments(&merge_paths(base, P)?));
+        // The query can only be removed by restating the path.
+        match relative_path(base.path(), target.path()) {
+          Some(path) => output.push_str(&path),
+          None => return Some(target.to_string()),
+        }
+      }
+    } else {
+      match relative_path(base.path(), target.path()) {
+        Some(path) => output.push_str(&path),
+        None => return Some(target.to_string()),
+      }
+
+      if let Some(query) = target.query() {
+        output.push('?');
+        output.push_str(query);
       }
+    }
+
+    if let Some(fragment) = target.fragment() {
+      output.push('#');
+      output.push_str(fragment);
+    }
+
+    Some(output)
+  }
+
+  /// Returns the shortest non-empty path reference from `base` to `target`.
+  ///
+  /// Returns `None` if `target` cannot be reached with a path reference.
+  fn relative_path(base: &str, target: &str) -> Option<String> {
+    // A merged path always begins with "/" so an empty path is unreachable.
+    if target.is_empty() {
+      return None;
+    }
+
+    // Directory segments of the base, ignoring the last (file) segment.
+    let base_dirs: &str = base.rfind('/').map_or("", |index| &base[..index]);
+    let base_dirs: Vec<&str> = base_dirs.split('/').skip(1).collect();
+
+    // Directory segments of the target and its last segment.
+    let index: usize = target.rfind('/').unwrap_or_default();
+    let target_dirs: Vec<&str> = target[..index].split('/').skip(1).collect();
+    let target_file: &str = &target[index + 1..];
+
+    let common: usize = base_dirs
+      .iter()
+      .zip(target_dirs.iter())
+      .take_while(|(a, b)| a == b)
+      .count();
+
+    let mut output: String = String::new();
+
+    for _ in common..base_dirs.len() {
+      output.push_str("../");
+    }
 
-      T.set_query(Q);
+    for segment in &target_dirs[common..] {
+      output.push_str(segment);
+      output.push('/');
     }
 
-    T.set_method(base.method()); // TODO: Remove? This in inherited via clone
-    T.set_method_id(base.method_id()); // TODO: Remove? This in inherited via clone
-    T.set_fragment(core.fragment(data));
+    output.push_str(target_file);
+
+    if output.is_empty() {
+      output.push('.');
+    } else if output.starts_with('/') {
+      // An empty first segment would be mistaken for an absolute path.
+      output.insert_str(0, "./");
+    } else if output
+      .split('/')
+      .next()
+      .is_some_and(|segment| segment.contains(':'))
+    {
+      // A colon in the first segment would be mistaken for a scheme.
+      output.insert_str(0, "./");
+    }
+
+    // An absolute-path reference may be shorter, unless it would be mistaken
+    // for a network-path reference.
+    if target.len() < output.len() && !target.starts_with("//") {
+      return Some(target.to_string());
+    }
 
-    Ok(T)
+    Some(output)
   }
 
   /// Merge Paths.
   ///
-  /// Merges a relative-path reference with the path of the base DID.
+  /// Merges a relative-path reference with the path of the base DID, writing
+  /// the result to `output`.
   ///
   /// [More Info](https://tools.ietf.org/html/rfc3986#section-5.2.3)
-  pub fn merge_paths<'a>(base: &'a DID, data: &'a str) -> Result<Cow<'a, str>> {
+  pub fn merge_paths(base: &DID, data: &str, output: &mut String) -> Result<()> {
     // Ensure the base DID has an authority component.
     //
     // The DID authority is `<method>:<method-specific-id>` so it should always
@@ -366,7 +846,10 @@ mod resolution {
     // reference's path.
 
     if base.path().is_empty() {
-      return Ok(data.into());
+      output.push('/');
+      output.push_str(data);
+
+      return Ok(());
     }
 
     // 2. Return a string consisting of the reference's path component
@@ -375,77 +858,109 @@ mod resolution {
     // path, or excluding the entire base URI path if it does not contain
     // any "/" characters).
 
-    let mut path: &str = base.path();
-
-    if let Some(index) = path.rfind('/') {
-      path = &path[..=index];
+    if let Some(index) = base.path().rfind('/') {
+      output.push_str(&base.path()[..=index]);
     }
 
-    Ok([path, data].join("").into())
+    output.push_str(data);
+
+    Ok(())
   }
 
   /// Remove Dot Segments.
   ///
   /// [More Info](https://tools.ietf.org/html/rfc3986#section-5.2.4)
-  pub fn remove_dot_segments(path: &str) -> Cow<str> {
-    fn next_segment(input: impl AsRef<[u8]>) -> Option<usize> {
-      match input.as_ref() {
+  pub fn remove_dot_segments(path: &str) -> Cow<'_, str> {
+    if !path.contains('.') {
+      return Cow::Borrowed(path);
+    }
+
+    let mut output: String = path.to_string();
+
+    remove_dot_segments_in_place(&mut output, 0);
+
+    Cow::Owned(output)
+  }
+
+  /// Removes dot segments from the path stored in `buffer[start..]`.
+  ///
+  /// The output is never longer than the input so segments are shifted
+  /// towards `start` within the same buffer and the remainder is truncated.
+  pub fn remove_dot_segments_in_place(buffer: &mut String, start: usize) {
+    fn next_segment(input: &[u8]) -> Option<usize> {
+      match input {
         [b'/', input @ ..] => next_segment(input).map(|index| index + 1),
         input => input.iter().position(|byte| *byte == b'/'),
       }
     }
 
-    let mut output: Path = Path::new();
-    let mut input: &[u8] = path.as_bytes();
+    fn pop(output: &[u8], start: usize, write: usize) -> usize {
+      output[start..write]
+        .iter()
+        .rposition(|byte| *byte == b'/')
+        .map_or(start, |index| start + index)
+    }
+
+    // SAFETY: Only whole segments are moved and only ASCII `.` and `/` bytes
+    // are removed or rewritten so the buffer remains valid UTF-8.
+    let bytes: &mut Vec<u8> = unsafe { buffer.as_mut_vec() };
+
+    let mut read: usize = start;
+    let mut write: usize = start;
 
     loop {
-      match input {
+      match &bytes[read..] {
         // Remove prefix ../
         [b'.', b'.', b'/', ..] => {
-          input = &input[3..];
+          read += 3;
         }
         // Remove prefix ./
         [b'.', b'/', ..] => {
-          input = &input[2..];
+          read += 2;
         }
         // Replace prefix /./
         [b'/', b'.', b'/', ..] => {
-          input = &input[2..];
+          read += 2;
         }
         // Replace prefix /.
         [b'/', b'.'] => {
-          input = &input[..1];
+          read += 1;
+          bytes[read] = b'/';
         }
         // Replace prefix /../
         [b'/', b'.', b'.', b'/', ..] => {
-          input = &input[3..];
-          output.pop();
+          read += 3;
+          write = pop(bytes, start, write);
         }
         // Replace prefix /..
         [b'/', b'.', b'.'] => {
-          input = &input[..2];
-          output.pop();
+          read += 2;
+          bytes[read] = b'/';
+          write = pop(bytes, start, write);
         }
         // Remove .
         [b'.'] => {
-          input = &input[1..];
+          read += 1;
         }
         // Remove ..
         [b'.', b'.'] => {
-          input = &input[2..];
+          read += 2;
         }
-        _ => {
-          if let Some(index) = next_segment(input) {
-            output.push(&input[..index]);
-            input = &input[index..];
-          } else {
-            output.push(input);
+        input => {
+          let length: usize = next_segment(input).unwrap_or(input.len());
+
+          bytes.copy_within(read..read + length, write);
+
+          read += length;
+          write += length;
+
+          if read == bytes.len() {
             break;
           }
         }
       }
     }
 
-    output.into()
+    bytes.truncate(write);
   }
 }
@@ -0,0 +1,146 @@
+//! Support for the [did:dht](https://did-dht.com/) method.
+//!
+//! A `did:dht` method-specific-id is the z-base-32 encoding of a 32-byte
+//! Ed25519 public key: the identity key of the DID.
+use alloc::string::String;
+
+use crate::did::DID;
+use crate::typed::TypedDid;
+
+/// The name of the did:dht method.
+pub const METHOD: &str = "dht";
+
+/// The fragment of the identity key verification method of a `did:dht` DID.
+pub const FRAGMENT: &str = "0";
+
+/// The length of an Ed25519 public key.
+pub const KEY_LENGTH: usize = 32;
+
+/// The length of a z-base-32 encoded Ed25519 public key.
+pub const LENGTH: usize = (KEY_LENGTH * 8).div_ceil(5);
+
+/// The z-base-32 alphabet.
+///
+/// [More Info](https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt)
+const ALPHABET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidCharacter => "Invalid Character",
+    InvalidLength => "Invalid Length",
+    InvalidMethod => "Invalid Method",
+    NonCanonical => "Non-Canonical Encoding",
+  }
+}
+
+crate::did_method! {
+  /// The did:dht method.
+  pub struct DidDht = METHOD, |did| decode(did).is_ok();
+}
+
+impl TypedDid<DidDht> {
+  /// Returns the decoded Ed25519 public key.
+  pub fn decode(&self) -> [u8; KEY_LENGTH] {
+    decode(self.as_did()).expect("valid did:dht")
+  }
+}
+
+/// Decodes the Ed25519 public key from the method-specific-id of a `did:dht`
+/// DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a `did:dht` DID, or the method-specific-id is
+/// not exactly 52 lowercase z-base-32 characters with zeroed trailing bits.
+pub fn decode(did: &DID) -> Result<[u8; KEY_LENGTH]> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  let data: &[u8] = did.method_id().as_bytes();
+
+  if data.len() != LENGTH {
+    return Err(Error::InvalidLength);
+  }
+
+  let mut output: [u8; KEY_LENGTH] = [0; KEY_LENGTH];
+  let mut buffer: u32 = 0;
+  let mut bits: u32 = 0;
+  let mut index: usize = 0;
+
+  for byte in data {
+    let value: usize = ALPHABET
+      .iter()
+      .position(|ch| ch == byte)
+      .ok_or(Error::InvalidCharacter)?;
+
+    buffer = (buffer << 5) | value as u32;
+    bits += 5;
+
+    if bits >= 8 {
+      bits -= 8;
+      output[index] = (buffer >> bits) as u8;
+      buffer &= (1 << bits) - 1;
+      index += 1;
+    }
+  }
+
+  // The unused bits of the final character must be zero.
+  if buffer != 0 {
+    return Err(Error::NonCanonical);
+  }
+
+  Ok(output)
+}
+
+/// Creates a `did:dht` [`DID`] from the given Ed25519 public `key`.
+pub fn encode(key: &[u8; KEY_LENGTH]) -> DID {
+  let mut output: String = String::with_capacity(LENGTH + 8);
+
+  output.push_str(DID::SCHEME);
+  output.push(':');
+  output.push_str(METHOD);
+  output.push(':');
+
+  let mut buffer: u32 = 0;
+  let mut bits: u32 = 0;
+
+  for byte in key.iter() {
+    buffer = (buffer << 8) | u32::from(*byte);
+    bits += 8;
+
+    while bits >= 5 {
+      bits -= 5;
+      output.push(ALPHABET[(buffer >> bits) as usize & 0x1F] as char);
+    }
+
+    buffer &= (1 << bits) - 1;
+  }
+
+  if bits > 0 {
+    output.push(ALPHABET[(buffer << (5 - bits)) as usize & 0x1F] as char);
+  }
+
+  // z-base-32 only produces characters valid in a method-specific-id.
+  DID::parse(output).expect("did:dht is a valid DID")
+}
+
+/// Returns the identity key verification method of a `did:dht` DID, eg.
+/// `did:dht:…#0`.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:dht` DID.
+pub fn verification_method(did: &DID) -> Result<DID> {
+  decode(did)?;
+
+  let mut output: DID = did.clone();
+
+  output.set_path("");
+  output.set_query(None);
+  output.set_fragment(Some(FRAGMENT));
+
+  Ok(output)
+}
@@ -0,0 +1,305 @@
+//! Support for the [did:ethr](https://github.com/decentralized-identity/ethr-did-resolver/blob/master/doc/did-method-spec.md) method.
+//!
+//! A `did:ethr` method-specific-id is an optional network followed by an
+//! Ethereum address or compressed secp256k1 public key:
+//! `[<network>:]<address-or-publicKey>`.
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::core::hex;
+use crate::did::DID;
+use crate::eip55;
+use crate::typed::TypedDid;
+
+/// The name of the did:ethr method.
+pub const METHOD: &str = "ethr";
+
+/// Named networks and their chain ids.
+pub const NETWORKS: &[(&str, u64)] = &[
+  ("mainnet", 0x1),
+  ("ropsten", 0x3),
+  ("rinkeby", 0x4),
+  ("goerli", 0x5),
+  ("kovan", 0x2a),
+  ("rsk", 0x1e),
+  ("rsk:testnet", 0x1f),
+  ("polygon", 0x89),
+  ("polygon:test", 0x13881),
+  ("sepolia", 0xaa36a7),
+];
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidChecksum => "Invalid Checksum",
+    InvalidIdentifier => "Invalid Identifier",
+    InvalidMethod => "Invalid Method",
+    InvalidNetwork => "Invalid Network",
+    InvalidPublicKey => "Invalid Public Key",
+  }
+}
+
+/// The Ethereum network of a `did:ethr` DID.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Network {
+  /// The default network (mainnet), when no network is given.
+  Mainnet,
+  /// A named network, eg. `goerli` or `rsk:testnet`.
+  Name(String),
+  /// A hex chain id, eg. `0x5`.
+  ChainId(u64),
+}
+
+impl Network {
+  /// Parses a [`Network`] from a named network or hex chain id.
+  ///
+  /// `mainnet` is parsed as [`Network::Mainnet`].
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not a valid network name or chain id.
+  pub fn parse(data: &str) -> Result<Self> {
+    if let Some(hex) = data.strip_prefix("0x") {
+      if hex.is_empty() || hex.len() > 16 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(Error::InvalidNetwork);
+      }
+
+      return u64::from_str_radix(hex, 16)
+        .map(Self::ChainId)
+        .map_err(|_| Error::InvalidNetwork);
+    }
+
+    if data.is_empty() || !data.split(':').all(is_name) {
+      return Err(Error::InvalidNetwork);
+    }
+
+    if data == "mainnet" {
+      return Ok(Self::Mainnet);
+    }
+
+    Ok(Self::Name(data.into()))
+  }
+
+  /// Returns the chain id of the network, if known.
+  pub fn chain_id(&self) -> Option<u64> {
+    match self {
+      Self::Mainnet => Some(1),
+      Self::Name(name) => NETWORKS
+        .iter()
+        .find(|(network, _)| network == name)
+        .map(|(_, chain_id)| *chain_id),
+      Self::ChainId(chain_id) => Some(*chain_id),
+    }
+  }
+}
+
+impl Display for Network {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    match self {
+      Self::Mainnet => f.write_str("mainnet"),
+      Self::Name(name) => f.write_str(name),
+      Self::ChainId(chain_id) => write!(f, "{:#x}", chain_id),
+    }
+  }
+}
+
+/// The Ethereum account of a `did:ethr` DID.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Identifier {
+  /// A `0x`-prefixed 20-byte hex address.
+  Address(String),
+  /// A compressed secp256k1 public key.
+  PublicKey([u8; 33]),
+}
+
+impl Identifier {
+  /// Parses an [`Identifier`] from a `0x`-prefixed hex address or compressed
+  /// public key.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not an address or compressed public key, or
+  /// is an address with an invalid EIP-55 checksum.
+  pub fn parse(data: &str) -> Result<Self> {
+    if eip55::is_address(data) {
+      if !eip55::verify(data) {
+        return Err(Error::InvalidChecksum);
+      }
+
+      return Ok(Self::Address(data.into()));
+    }
+
+    let digits: &str = data.strip_prefix("0x").ok_or(Error::InvalidIdentifier)?;
+
+    if digits.len() != 66 || !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+      return Err(Error::InvalidIdentifier);
+    }
+
+    let mut bytes: [u8; 33] = [0; 33];
+
+    for (byte, pair) in bytes.iter_mut().zip(digits.as_bytes().chunks(2)) {
+      *byte = (hex(pair[0]) << 4) | hex(pair[1]);
+    }
+
+    if !matches!(bytes[0], 0x02 | 0x03) {
+      return Err(Error::InvalidPublicKey);
+    }
+
+    Ok(Self::PublicKey(bytes))
+  }
+
+  /// Returns the address, if the identifier is an address.
+  pub fn address(&self) -> Option<&str> {
+    match self {
+      Self::Address(address) => Some(address),
+      Self::PublicKey(_) => None,
+    }
+  }
+
+  /// Returns the compressed public key, if the identifier is a public key.
+  pub fn public_key(&self) -> Option<&[u8; 33]> {
+    match self {
+      Self::Address(_) => None,
+      Self::PublicKey(public_key) => Some(public_key),
+    }
+  }
+}
+
+impl Display for Identifier {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    match self {
+      Self::Address(address) => f.write_str(address),
+      Self::PublicKey(public_key) => {
+        f.write_str("0x")?;
+
+        for byte in public_key.iter() {
+          write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+      }
+    }
+  }
+}
+
+/// The decoded method-specific-id of a `did:ethr` DID.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EthrDid {
+  network: Network,
+  identifier: Identifier,
+}
+
+impl EthrDid {
+  /// Creates a new [`EthrDid`] from the given `network` and `identifier`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `network` is not a valid network name, or `identifier`
+  /// is not a valid address.
+  pub fn new(network: Network, identifier: Identifier) -> Result<Self> {
+    if let Network::Name(name) = &network {
+      if name.starts_with("0x") || Network::parse(name)? != network {
+        return Err(Error::InvalidNetwork);
+      }
+    }
+
+    if let Identifier::Address(address) = &identifier {
+      if !eip55::is_address(address) {
+        return Err(Error::InvalidIdentifier);
+      }
+
+      if !eip55::verify(address) {
+        return Err(Error::InvalidChecksum);
+      }
+    }
+
+    Ok(Self {
+      network,
+      identifier,
+    })
+  }
+
+  /// Returns the network of the DID.
+  #[inline]
+  pub const fn network(&self) -> &Network {
+    &self.network
+  }
+
+  /// Returns the address or public key of the DID.
+  #[inline]
+  pub const fn identifier(&self) -> &Identifier {
+    &self.identifier
+  }
+
+  /// Returns the chain id of the network, if known.
+  #[inline]
+  pub fn chain_id(&self) -> Option<u64> {
+    self.network.chain_id()
+  }
+}
+
+impl Display for EthrDid {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    match self.network {
+      Network::Mainnet => write!(f, "{}", self.identifier),
+      ref network => write!(f, "{}:{}", network, self.identifier),
+    }
+  }
+}
+
+crate::did_method! {
+  /// The did:ethr method.
+  pub struct DidEthr = METHOD, |did| decode(did).is_ok();
+}
+
+impl TypedDid<DidEthr> {
+  /// Returns the decoded [`EthrDid`].
+  pub fn decode(&self) -> EthrDid {
+    decode(self.as_did()).expect("valid did:ethr")
+  }
+}
+
+/// Decodes the [`EthrDid`] from the method-specific-id of a `did:ethr` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:ethr` DID.
+pub fn decode(did: &DID) -> Result<EthrDid> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  let segments: Vec<&str> = did.method_id_segments().collect();
+
+  // The identifier is always the last segment; network names may contain `:`.
+  let (identifier, network): (&&str, &[&str]) =
+    segments.split_last().ok_or(Error::InvalidIdentifier)?;
+
+  let network: Network = match network {
+    [] => Network::Mainnet,
+    network => Network::parse(&network.join(":"))?,
+  };
+
+  EthrDid::new(network, Identifier::parse(identifier)?)
+}
+
+/// Creates a `did:ethr` [`DID`] from the given `ethr`.
+///
+/// The default network is omitted from the method-specific-id.
+pub fn encode(ethr: &EthrDid) -> DID {
+  // Networks and identifiers are validated to contain only idchars and `:`.
+  DID::parse(format!("{}:{}:{}", DID::SCHEME, METHOD, ethr)).expect("did:ethr is a valid DID")
+}
+
+#[inline(always)]
+fn is_name(segment: &str) -> bool {
+  !segment.is_empty()
+    && segment
+      .bytes()
+      .all(|byte| matches!(byte, b'-' | b'a'..=b'z' | b'0'..=b'9'))
+}
@@ -0,0 +1,358 @@
+//! Support for the [did:indy](https://hyperledger.github.io/indy-did-method/)
+//! method and the legacy [did:sov](https://sovrin-foundation.github.io/sovrin/spec/did-method-spec-template.html)
+//! method.
+//!
+//! A `did:indy` method-specific-id is a ledger namespace followed by a nym:
+//! `<namespace>[:<sub-namespace>]:<nym>`. A `did:sov` method-specific-id is
+//! only the nym.
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString as _;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use percent_encoding::percent_decode_str;
+
+use crate::did::DID;
+use crate::typed::TypedDid;
+
+/// The name of the did:indy method.
+pub const METHOD: &str = "indy";
+
+/// The name of the did:sov method.
+pub const SOV_METHOD: &str = "sov";
+
+/// The path prefix of ledger objects.
+pub const ANONCREDS: &str = "/anoncreds/v0/";
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidMethod => "Invalid Method",
+    InvalidNamespace => "Invalid Namespace",
+    InvalidNym => "Invalid Nym",
+    InvalidObject => "Invalid Object",
+  }
+}
+
+/// An Indy nym: the base58 encoding of a 16-byte or 32-byte value.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Nym(String);
+
+impl Nym {
+  /// Creates a new [`Nym`] from the given base58 encoded `data`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not the base58 encoding of 16 or 32 bytes.
+  pub fn new(data: &str) -> Result<Self> {
+    match bs58::decode(data).into_vec() {
+      Ok(bytes) if matches!(bytes.len(), 16 | 32) => Ok(Self(data.into())),
+      _ => Err(Error::InvalidNym),
+    }
+  }
+
+  /// Returns the decoded bytes of the nym.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    bs58::decode(&self.0).into_vec().expect("valid nym")
+  }
+
+  /// Returns the nym as a string slice.
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Display for Nym {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+impl AsRef<str> for Nym {
+  #[inline]
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+/// The decoded method-specific-id of a `did:indy` DID.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IndyDid {
+  namespace: String,
+  sub_namespace: Option<String>,
+  nym: Nym,
+}
+
+impl IndyDid {
+  /// Creates a new [`IndyDid`] from the given ledger `namespace`, optional
+  /// `sub_namespace`, and `nym`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if either namespace is empty or contains characters other
+  /// than lowercase letters, digits, `-`, and `_`.
+  pub fn new(namespace: &str, sub_namespace: Option<&str>, nym: Nym) -> Result<Self> {
+    if !is_namespace(namespace) || !sub_namespace.is_none_or(is_namespace) {
+      return Err(Error::InvalidNamespace);
+    }
+
+    Ok(Self {
+      namespace: namespace.into(),
+      sub_namespace: sub_namespace.map(Into::into),
+      nym,
+    })
+  }
+
+  /// Returns the ledger namespace, eg. `sovrin`.
+  #[inline]
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns the ledger sub-namespace, eg. `staging`.
+  #[inline]
+  pub fn sub_namespace(&self) -> Option<&str> {
+    self.sub_namespace.as_deref()
+  }
+
+  /// Returns the full ledger name, eg. `sovrin:staging`.
+  pub fn ledger(&self) -> String {
+    match self.sub_namespace.as_deref() {
+      Some(sub_namespace) => format!("{}:{}", self.namespace, sub_namespace),
+      None => self.namespace.clone(),
+    }
+  }
+
+  /// Returns the nym.
+  #[inline]
+  pub const fn nym(&self) -> &Nym {
+    &self.nym
+  }
+}
+
+impl Display for IndyDid {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    write!(f, "{}:{}", self.ledger(), self.nym)
+  }
+}
+
+/// A ledger object referenced by a `did:indy` DID URL path.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum LedgerObject {
+  /// `/anoncreds/v0/SCHEMA/<name>/<version>`
+  Schema { name: String, version: String },
+  /// `/anoncreds/v0/CLAIM_DEF/<schema-seq-no>/<tag>`
+  ClaimDef { schema_seq_no: u64, tag: String },
+  /// `/anoncreds/v0/REV_REG_DEF/<schema-seq-no>/<claim-def-tag>/<tag>`
+  RevRegDef {
+    schema_seq_no: u64,
+    claim_def_tag: String,
+    tag: String,
+  },
+  /// `/anoncreds/v0/REV_REG_ENTRY/<schema-seq-no>/<claim-def-tag>/<tag>`
+  RevRegEntry {
+    schema_seq_no: u64,
+    claim_def_tag: String,
+    tag: String,
+  },
+}
+
+impl LedgerObject {
+  /// Parses a [`LedgerObject`] from a DID URL `path`.
+  ///
+  /// Segments are percent-decoded.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `path` does not reference a known ledger object.
+  pub fn parse(path: &str) -> Result<Self> {
+    let path: &str = path.strip_prefix(ANONCREDS).ok_or(Error::InvalidObject)?;
+
+    let segments: Vec<String> = path
+      .split('/')
+      .map(|segment| {
+        percent_decode_str(segment)
+          .decode_utf8()
+          .map(|segment| segment.into_owned())
+          .map_err(|_| Error::InvalidObject)
+      })
+      .collect::<Result<_>>()?;
+
+    if segments[1..].iter().any(String::is_empty) {
+      return Err(Error::InvalidObject);
+    }
+
+    match segments.as_slice() {
+      [kind, name, version] if kind == "SCHEMA" => Ok(Self::Schema {
+        name: name.clone(),
+        version: version.clone(),
+      }),
+      [kind, seq_no, tag] if kind == "CLAIM_DEF" => Ok(Self::ClaimDef {
+        schema_seq_no: seq_no_from_str(seq_no)?,
+        tag: tag.clone(),
+      }),
+      [kind, seq_no, claim_def_tag, tag] if kind == "REV_REG_DEF" => Ok(Self::RevRegDef {
+        schema_seq_no: seq_no_from_str(seq_no)?,
+        claim_def_tag: claim_def_tag.clone(),
+        tag: tag.clone(),
+      }),
+      [kind, seq_no, claim_def_tag, tag] if kind == "REV_REG_ENTRY" => Ok(Self::RevRegEntry {
+        schema_seq_no: seq_no_from_str(seq_no)?,
+        claim_def_tag: claim_def_tag.clone(),
+        tag: tag.clone(),
+      }),
+      _ => Err(Error::InvalidObject),
+    }
+  }
+}
+
+crate::did_method! {
+  /// The did:indy method.
+  pub struct DidIndy = METHOD, |did| decode(did).is_ok();
+}
+
+impl TypedDid<DidIndy> {
+  /// Returns the decoded [`IndyDid`].
+  pub fn decode(&self) -> IndyDid {
+    decode(self.as_did()).expect("valid did:indy")
+  }
+}
+
+/// Decodes the [`IndyDid`] from the method-specific-id of a `did:indy` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:indy` DID.
+pub fn decode(did: &DID) -> Result<IndyDid> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  let segments: Vec<&str> = did.method_id_segments().collect();
+
+  match segments.as_slice() {
+    [namespace, nym] => IndyDid::new(namespace, None, Nym::new(nym)?),
+    [namespace, sub_namespace, nym] => {
+      IndyDid::new(namespace, Some(*sub_namespace), Nym::new(nym)?)
+    }
+    [_] => Err(Error::InvalidNamespace),
+    _ => Err(Error::InvalidNym),
+  }
+}
+
+crate::did_method! {
+  /// The did:sov method.
+  pub struct DidSov = SOV_METHOD, |did| decode_sov(did).is_ok();
+}
+
+impl TypedDid<DidSov> {
+  /// Returns the decoded [`Nym`].
+  pub fn decode(&self) -> Nym {
+    decode_sov(self.as_did()).expect("valid did:sov")
+  }
+}
+
+/// Decodes the [`Nym`] from the method-specific-id of a `did:sov` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:sov` DID.
+pub fn decode_sov(did: &DID) -> Result<Nym> {
+  if did.method() != SOV_METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  Nym::new(did.method_id())
+}
+
+/// Creates a `did:indy` [`DID`] from the given `indy` identifier.
+pub fn encode(indy: &IndyDid) -> DID {
+  // Namespaces and nyms are validated to contain only idchars.
+  DID::parse(format!("{}:{}:{}", DID::SCHEME, METHOD, indy)).expect("did:indy is a valid DID")
+}
+
+/// Creates a `did:sov` [`DID`] from the given `nym`.
+pub fn encode_sov(nym: &Nym) -> DID {
+  // base58 only produces characters valid in a method-specific-id.
+  DID::parse(format!("{}:{}:{}", DID::SCHEME, SOV_METHOD, nym)).expect("did:sov is a valid DID")
+}
+
+/// Converts a `did:sov` DID into a `did:indy` DID on the given `ledger`, eg.
+/// `sovrin` or `sovrin:staging`.
+///
+/// The path, query, and fragment are preserved.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:sov` DID or `ledger` is not a
+/// valid namespace.
+pub fn sov_to_indy(did: &DID, ledger: &str) -> Result<DID> {
+  let nym: Nym = decode_sov(did)?;
+
+  let indy: IndyDid = match ledger.find(':') {
+    Some(index) => IndyDid::new(&ledger[..index], Some(&ledger[index + 1..]), nym)?,
+    None => IndyDid::new(ledger, None, nym)?,
+  };
+
+  let mut output: DID = did.clone();
+
+  output.set_method(METHOD);
+  output.set_method_id(indy.to_string());
+
+  Ok(output)
+}
+
+/// Converts a `did:indy` DID into a `did:sov` DID, discarding the ledger
+/// namespace.
+///
+/// The path, query, and fragment are preserved.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:indy` DID.
+pub fn indy_to_sov(did: &DID) -> Result<DID> {
+  let indy: IndyDid = decode(did)?;
+
+  let mut output: DID = did.clone();
+
+  output.set_method(SOV_METHOD);
+  output.set_method_id(indy.nym().as_str());
+
+  Ok(output)
+}
+
+/// Returns the ledger object referenced by the path of a `did:indy` DID URL,
+/// if any.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:indy` DID, or has a path that
+/// does not reference a known ledger object.
+pub fn ledger_object(did: &DID) -> Result<Option<LedgerObject>> {
+  decode(did)?;
+
+  match did.path() {
+    "" => Ok(None),
+    path => LedgerObject::parse(path).map(Some),
+  }
+}
+
+fn is_namespace(data: &str) -> bool {
+  !data.is_empty()
+    && data
+      .bytes()
+      .all(|byte| matches!(byte, b'-' | b'_' | b'a'..=b'z' | b'0'..=b'9'))
+}
+
+fn seq_no_from_str(data: &str) -> Result<u64> {
+  if !data.bytes().all(|byte| byte.is_ascii_digit()) {
+    return Err(Error::InvalidObject);
+  }
+
+  data.parse().map_err(|_| Error::InvalidObject)
+}
@@ -0,0 +1,333 @@
+//! Support for the [did:ion](https://identity.foundation/ion/) method and
+//! other [Sidetree](https://identity.foundation/sidetree/spec/) based methods.
+//!
+//! A short-form Sidetree DID ends with the unique suffix of the DID: the
+//! base64url encoded multihash of its initial suffix data. A long-form DID
+//! additionally carries the initial state, either as a trailing
+//! method-specific-id segment or, in the legacy form, as an `initial-state`
+//! query parameter.
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use serde_json::Map;
+use serde_json::Value;
+use sha2::Digest as _;
+use sha2::Sha256;
+
+use crate::did::DID;
+use crate::jcs;
+use crate::typed::TypedDid;
+
+/// The name of the did:ion method.
+pub const METHOD: &str = "ion";
+
+/// The name of the legacy initial state query parameter.
+pub const INITIAL_STATE: &str = "initial-state";
+
+/// The multihash code of SHA2-256.
+const SHA2_256: u8 = 0x12;
+
+/// The length of a SHA2-256 digest.
+const SHA2_256_LENGTH: u8 = 32;
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    HashMismatch => "Hash Mismatch",
+    InvalidEncoding => "Invalid Encoding",
+    InvalidInitialState => "Invalid Initial State",
+    InvalidJson => "Invalid Json",
+    InvalidMethod => "Invalid Method",
+    InvalidSuffix => "Invalid Suffix",
+  }
+}
+
+/// The initial state of a Sidetree DID: the suffix data and delta of its
+/// create operation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InitialState {
+  suffix_data: Map<String, Value>,
+  delta: Map<String, Value>,
+}
+
+impl InitialState {
+  /// Creates a new [`InitialState`] from the given `suffix_data` and `delta`.
+  pub const fn new(suffix_data: Map<String, Value>, delta: Map<String, Value>) -> Self {
+    Self { suffix_data, delta }
+  }
+
+  /// Returns the suffix data of the create operation.
+  #[inline]
+  pub const fn suffix_data(&self) -> &Map<String, Value> {
+    &self.suffix_data
+  }
+
+  /// Returns the delta of the create operation.
+  #[inline]
+  pub const fn delta(&self) -> &Map<String, Value> {
+    &self.delta
+  }
+
+  /// Returns the unique DID suffix: the base64url encoded SHA2-256 multihash
+  /// of the canonicalized suffix data.
+  pub fn suffix(&self) -> String {
+    let data: Vec<u8> = to_vec(&self.suffix_data);
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(SHA2_256_LENGTH as usize + 2);
+
+    bytes.push(SHA2_256);
+    bytes.push(SHA2_256_LENGTH);
+    bytes.extend_from_slice(&Sha256::digest(&data));
+
+    URL_SAFE_NO_PAD.encode(bytes)
+  }
+
+  /// Returns the base64url encoded long-form method-specific-id segment.
+  pub fn to_long_form(&self) -> String {
+    let mut object: Map<String, Value> = Map::new();
+
+    object.insert("suffixData".into(), Value::Object(self.suffix_data.clone()));
+    object.insert("delta".into(), Value::Object(self.delta.clone()));
+
+    URL_SAFE_NO_PAD.encode(to_vec(&object))
+  }
+
+  /// Decodes an [`InitialState`] from a long-form method-specific-id segment.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not a base64url encoded create operation.
+  pub fn from_long_form(data: &str) -> Result<Self> {
+    let mut object: Map<String, Value> = decode_json(data)?;
+
+    match (object.remove("suffixData"), object.remove("delta")) {
+      (Some(Value::Object(suffix_data)), Some(Value::Object(delta))) => {
+        Ok(Self::new(suffix_data, delta))
+      }
+      _ => Err(Error::InvalidInitialState),
+    }
+  }
+
+  /// Decodes an [`InitialState`] from a legacy `initial-state` query value:
+  /// `<suffix-data>.<delta>`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not a valid legacy initial state.
+  pub fn from_legacy(data: &str) -> Result<Self> {
+    let mut parts = data.split('.');
+
+    match (parts.next(), parts.next(), parts.next()) {
+      (Some(suffix_data), Some(delta), None) => {
+        Ok(Self::new(decode_json(suffix_data)?, decode_json(delta)?))
+      }
+      _ => Err(Error::InvalidInitialState),
+    }
+  }
+}
+
+/// A decoded Sidetree DID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sidetree {
+  short_form: DID,
+  initial_state: Option<InitialState>,
+}
+
+impl Sidetree {
+  /// Returns the short-form DID, eg. `did:ion:EiA…`.
+  #[inline]
+  pub const fn short_form(&self) -> &DID {
+    &self.short_form
+  }
+
+  /// Returns the unique DID suffix.
+  pub fn suffix(&self) -> &str {
+    // The short form always ends with the suffix segment.
+    self
+      .short_form
+      .method_id_segments()
+      .next_back()
+      .unwrap_or_default()
+  }
+
+  /// Returns the initial state of a long-form DID.
+  #[inline]
+  pub const fn initial_state(&self) -> Option<&InitialState> {
+    self.initial_state.as_ref()
+  }
+
+  /// Returns the long-form DID, if the initial state is known.
+  pub fn long_form(&self) -> Option<DID> {
+    self.initial_state.as_ref().map(|state| {
+      let mut output: DID = self.short_form.clone();
+
+      output.set_method_id(format!("{}:{}", output.method_id(), state.to_long_form()));
+      output
+    })
+  }
+}
+
+crate::did_method! {
+  /// The did:ion method.
+  pub struct DidIon = METHOD, |did| decode(did).is_ok();
+}
+
+impl TypedDid<DidIon> {
+  /// Returns the decoded [`Sidetree`] DID.
+  pub fn decode(&self) -> Sidetree {
+    decode(self.as_did()).expect("valid did:ion")
+  }
+}
+
+/// Decodes a short-form, long-form, or legacy long-form `did:ion` DID.
+///
+/// Any network prefix of the method-specific-id (eg. `did:ion:test:…`) is
+/// preserved. The path, query, and fragment are not part of the result.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a `did:ion` DID, has no valid unique
+/// suffix, or the suffix does not match the initial state.
+pub fn decode(did: &DID) -> Result<Sidetree> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  let segments: Vec<&str> = did.method_id_segments().collect();
+
+  let (last, rest): (&&str, &[&str]) = segments.split_last().ok_or(Error::InvalidSuffix)?;
+
+  let (prefix, suffix, long_form): (&[&str], &str, Option<&str>) = match rest.split_last() {
+    Some((suffix, prefix)) if !is_suffix(last) => (prefix, suffix, Some(last)),
+    _ => (rest, last, None),
+  };
+
+  if !is_suffix(suffix) {
+    return Err(Error::InvalidSuffix);
+  }
+
+  let legacy: Option<String> = legacy_initial_state(did);
+
+  let initial_state: Option<InitialState> = match (long_form, legacy) {
+    (Some(_), Some(_)) => return Err(Error::InvalidInitialState),
+    (Some(data), None) => Some(InitialState::from_long_form(data)?),
+    (None, Some(data)) => Some(InitialState::from_legacy(&data)?),
+    (None, None) => None,
+  };
+
+  if let Some(state) = initial_state.as_ref() {
+    if state.suffix() != suffix {
+      return Err(Error::HashMismatch);
+    }
+  }
+
+  let mut method_id: String = prefix.join(":");
+
+  if !method_id.is_empty() {
+    method_id.push(':');
+  }
+
+  method_id.push_str(suffix);
+
+  let mut short_form: DID = did.clone();
+
+  short_form.set_method_id(method_id);
+  short_form.set_path("");
+  short_form.set_query(None);
+  short_form.set_fragment(None);
+
+  Ok(Sidetree {
+    short_form,
+    initial_state,
+  })
+}
+
+/// Returns the short form of a Sidetree DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid Sidetree DID.
+pub fn short_form(did: &DID) -> Result<DID> {
+  decode(did).map(|sidetree| sidetree.short_form)
+}
+
+/// Returns the long form of a Sidetree DID with the given initial `state`.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid Sidetree DID, or its suffix does not
+/// match `state`.
+pub fn long_form(did: &DID, state: &InitialState) -> Result<DID> {
+  let mut sidetree: Sidetree = decode(did)?;
+
+  if sidetree.suffix() != state.suffix() {
+    return Err(Error::HashMismatch);
+  }
+
+  sidetree.initial_state = Some(state.clone());
+
+  Ok(sidetree.long_form().expect("initial state"))
+}
+
+/// Creates a long-form `did:ion` [`DID`] from the given initial `state`.
+pub fn encode(state: &InitialState) -> DID {
+  let data: String = format!(
+    "{}:{}:{}:{}",
+    DID::SCHEME,
+    METHOD,
+    state.suffix(),
+    state.to_long_form()
+  );
+
+  // base64url only produces characters valid in a method-specific-id.
+  DID::parse(data).expect("did:ion is a valid DID")
+}
+
+/// Returns the value of the legacy `initial-state` or `-<method>-initial-state`
+/// query parameter.
+fn legacy_initial_state(did: &DID) -> Option<String> {
+  did.query_pairs().find_map(|(key, value)| {
+    let key: &str = key
+      .strip_prefix('-')
+      .and_then(|key| key.strip_prefix(did.method()))
+      .and_then(|key| key.strip_prefix('-'))
+      .unwrap_or_else(|| key.as_ref());
+
+    if key == INITIAL_STATE {
+      Some(value.into_owned())
+    } else {
+      None
+    }
+  })
+}
+
+/// Returns `true` if `data` is a base64url encoded SHA2-256 multihash.
+fn is_suffix(data: &str) -> bool {
+  match URL_SAFE_NO_PAD.decode(data) {
+    Ok(bytes) => {
+      bytes.len() == SHA2_256_LENGTH as usize + 2
+        && bytes[0] == SHA2_256
+        && bytes[1] == SHA2_256_LENGTH
+    }
+    Err(_) => false,
+  }
+}
+
+fn decode_json(data: &str) -> Result<Map<String, Value>> {
+  let bytes: Vec<u8> = URL_SAFE_NO_PAD
+    .decode(data)
+    .map_err(|_| Error::InvalidEncoding)?;
+
+  match serde_json::from_slice(&bytes) {
+    Ok(Value::Object(object)) => Ok(object),
+    Ok(_) => Err(Error::InvalidInitialState),
+    Err(_) => Err(Error::InvalidJson),
+  }
+}
+
+fn to_vec(object: &Map<String, Value>) -> Vec<u8> {
+  jcs::to_string(&Value::Object(object.clone())).into_bytes()
+}
@@ -0,0 +1,139 @@
+//! Support for the [did:jwk](https://github.com/quartzjer/did-jwk/blob/main/spec.md) method.
+//!
+//! A `did:jwk` method-specific-id is the base64url encoding of a JSON Web Key
+//! (JWK) serialized as UTF-8 JSON.
+use alloc::string::String;
+use alloc::vec::Vec;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::did::DID;
+use crate::jcs;
+use crate::typed::TypedDid;
+
+/// The name of the did:jwk method.
+pub const METHOD: &str = "jwk";
+
+/// The fragment of the single verification method of a `did:jwk` DID.
+pub const FRAGMENT: &str = "0";
+
+/// JWK members that may only be present in private or symmetric keys.
+///
+/// [More Info](https://www.iana.org/assignments/jose/jose.xhtml#web-key-parameters)
+pub const PRIVATE_MEMBERS: &[&str] = &["d", "p", "q", "dp", "dq", "qi", "oth", "k"];
+
+/// A JSON Web Key.
+pub type Jwk = Map<String, Value>;
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidEncoding => "Invalid Encoding",
+    InvalidJson => "Invalid Json",
+    InvalidJwk => "Invalid Jwk",
+    InvalidMethod => "Invalid Method",
+    PrivateKey => "Private Key",
+  }
+}
+
+crate::did_method! {
+  /// The did:jwk method.
+  pub struct DidJwk = METHOD, |did| decode(did).is_ok();
+}
+
+impl TypedDid<DidJwk> {
+  /// Returns the decoded public [`Jwk`].
+  pub fn decode(&self) -> Jwk {
+    decode(self.as_did()).expect("valid did:jwk")
+  }
+}
+
+/// Decodes the public [`Jwk`] from the method-specific-id of a `did:jwk` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:jwk` DID or the JWK is not a
+/// public key.
+pub fn decode(did: &DID) -> Result<Jwk> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  let bytes: Vec<u8> = URL_SAFE_NO_PAD
+    .decode(did.method_id())
+    .map_err(|_| Error::InvalidEncoding)?;
+
+  let jwk: Jwk = match serde_json::from_slice(&bytes) {
+    Ok(Value::Object(jwk)) => jwk,
+    Ok(_) => return Err(Error::InvalidJwk),
+    Err(_) => return Err(Error::InvalidJson),
+  };
+
+  validate(&jwk)?;
+
+  Ok(jwk)
+}
+
+/// Creates a `did:jwk` [`DID`] from the given public `jwk`.
+///
+/// The JWK is serialized with the JSON Canonicalization Scheme so equal keys
+/// always produce the same DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `jwk` is not a public key.
+pub fn encode(jwk: &Jwk) -> Result<DID> {
+  validate(jwk)?;
+
+  let json: String = jcs::to_string(&Value::Object(jwk.clone()));
+
+  let mut output: String = String::with_capacity(json.len() * 4 / 3 + 12);
+
+  output.push_str(DID::SCHEME);
+  output.push(':');
+  output.push_str(METHOD);
+  output.push(':');
+
+  URL_SAFE_NO_PAD.encode_string(json, &mut output);
+
+  // base64url only produces characters valid in a method-specific-id.
+  Ok(DID::parse(output).expect("did:jwk is a valid DID"))
+}
+
+/// Returns the verification method of a `did:jwk` DID, eg. `did:jwk:…#0`.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:jwk` DID.
+pub fn verification_method(did: &DID) -> Result<DID> {
+  decode(did)?;
+
+  let mut output: DID = did.clone();
+
+  output.set_path("");
+  output.set_query(None);
+  output.set_fragment(Some(FRAGMENT));
+
+  Ok(output)
+}
+
+/// Ensures `jwk` is a public key.
+fn validate(jwk: &Jwk) -> Result<()> {
+  match jwk.get("kty") {
+    Some(Value::String(kty)) if kty == "oct" => return Err(Error::PrivateKey),
+    Some(Value::String(_)) => {}
+    _ => return Err(Error::InvalidJwk),
+  }
+
+  if PRIVATE_MEMBERS
+    .iter()
+    .any(|member| jwk.contains_key(*member))
+  {
+    return Err(Error::PrivateKey);
+  }
+
+  Ok(())
+}
@@ -0,0 +1,282 @@
+//! Support for the [did:key](https://w3c-ccg.github.io/did-method-key/) method.
+//!
+//! A `did:key` method-specific-id is a base58btc multibase (`z…`) encoding of
+//! a multicodec-prefixed public key.
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::did::DID;
+use crate::typed::TypedDid;
+
+/// The name of the did:key method.
+pub const METHOD: &str = "key";
+
+/// The multibase prefix for base58btc.
+pub const BASE58BTC: char = 'z';
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidKeyLength => "Invalid Key Length",
+    InvalidMethod => "Invalid Method",
+    InvalidMulticodec => "Invalid Multicodec",
+    InvalidMultibase => "Invalid Multibase",
+    UnsupportedCodec => "Unsupported Codec",
+  }
+}
+
+/// A multicodec public key type.
+///
+/// [More Info](https://github.com/multiformats/multicodec/blob/master/table.csv)
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Codec {
+  Ed25519,
+  X25519,
+  Secp256k1,
+  P256,
+  P384,
+  P521,
+  Bls12381G1,
+  Bls12381G2,
+  Rsa,
+}
+
+impl Codec {
+  /// Returns the multicodec code of the key type.
+  pub const fn code(&self) -> u64 {
+    match self {
+      Self::Ed25519 => 0xed,
+      Self::X25519 => 0xec,
+      Self::Secp256k1 => 0xe7,
+      Self::P256 => 0x1200,
+      Self::P384 => 0x1201,
+      Self::P521 => 0x1202,
+      Self::Bls12381G1 => 0xea,
+      Self::Bls12381G2 => 0xeb,
+      Self::Rsa => 0x1205,
+    }
+  }
+
+  /// Returns the key type with the given multicodec `code`, if supported.
+  pub const fn from_code(code: u64) -> Option<Self> {
+    match code {
+      0xed => Some(Self::Ed25519),
+      0xec => Some(Self::X25519),
+      0xe7 => Some(Self::Secp256k1),
+      0x1200 => Some(Self::P256),
+      0x1201 => Some(Self::P384),
+      0x1202 => Some(Self::P521),
+      0xea => Some(Self::Bls12381G1),
+      0xeb => Some(Self::Bls12381G2),
+      0x1205 => Some(Self::Rsa),
+      _ => None,
+    }
+  }
+
+  /// Returns the multicodec name of the key type.
+  pub const fn name(&self) -> &'static str {
+    match self {
+      Self::Ed25519 => "ed25519-pub",
+      Self::X25519 => "x25519-pub",
+      Self::Secp256k1 => "secp256k1-pub",
+      Self::P256 => "p256-pub",
+      Self::P384 => "p384-pub",
+      Self::P521 => "p521-pub",
+      Self::Bls12381G1 => "bls12_381-g1-pub",
+      Self::Bls12381G2 => "bls12_381-g2-pub",
+      Self::Rsa => "rsa-pub",
+    }
+  }
+
+  /// Returns the expected length of the (compressed) key bytes, if fixed.
+  pub const fn key_length(&self) -> Option<usize> {
+    match self {
+      Self::Ed25519 => Some(32),
+      Self::X25519 => Some(32),
+      Self::Secp256k1 => Some(33),
+      Self::P256 => Some(33),
+      Self::P384 => Some(49),
+      Self::P521 => Some(67),
+      Self::Bls12381G1 => Some(48),
+      Self::Bls12381G2 => Some(96),
+      Self::Rsa => None,
+    }
+  }
+}
+
+impl Display for Codec {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_str(self.name())
+  }
+}
+
+/// A multicodec-tagged public key.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PublicKey {
+  codec: Codec,
+  bytes: Vec<u8>,
+}
+
+impl PublicKey {
+  /// Creates a new [`PublicKey`] from the given `codec` and key `bytes`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `bytes` is not the expected length for `codec`.
+  pub fn new(codec: Codec, bytes: impl Into<Vec<u8>>) -> Result<Self> {
+    let bytes: Vec<u8> = bytes.into();
+
+    match codec.key_length() {
+      Some(length) if length != bytes.len() => Err(Error::InvalidKeyLength),
+      _ if bytes.is_empty() => Err(Error::InvalidKeyLength),
+      _ => Ok(Self { codec, bytes }),
+    }
+  }
+
+  /// Decodes a [`PublicKey`] from a base58btc multibase string (`z…`).
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not a valid multibase-encoded key.
+  pub fn from_multibase(data: &str) -> Result<Self> {
+    let data: &str = data
+      .strip_prefix(BASE58BTC)
+      .ok_or(Error::InvalidMultibase)?;
+
+    let bytes: Vec<u8> = bs58::decode(data)
+      .into_vec()
+      .map_err(|_| Error::InvalidMultibase)?;
+
+    let (code, bytes): (u64, &[u8]) = varint_decode(&bytes).ok_or(Error::InvalidMulticodec)?;
+    let codec: Codec = Codec::from_code(code).ok_or(Error::UnsupportedCodec)?;
+
+    Self::new(codec, bytes)
+  }
+
+  /// Returns the key type.
+  #[inline]
+  pub const fn codec(&self) -> Codec {
+    self.codec
+  }
+
+  /// Returns the raw key bytes, without the multicodec prefix.
+  #[inline]
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.bytes
+  }
+
+  /// Consumes the [`PublicKey`] and returns the raw key bytes.
+  #[inline]
+  pub fn into_bytes(self) -> Vec<u8> {
+    self.bytes
+  }
+
+  /// Returns the base58btc multibase encoding of the multicodec-prefixed key.
+  pub fn to_multibase(&self) -> String {
+    let mut bytes: Vec<u8> = Vec::with_capacity(self.bytes.len() + 3);
+
+    varint_encode(self.codec.code(), &mut bytes);
+    bytes.extend_from_slice(&self.bytes);
+
+    let mut output: String = String::with_capacity(bytes.len() * 2);
+
+    output.push(BASE58BTC);
+    output.push_str(&bs58::encode(bytes).into_string());
+    output
+  }
+}
+
+crate::did_method! {
+  /// The did:key method.
+  pub struct DidKey = METHOD, |did| decode(did).is_ok();
+}
+
+impl TypedDid<DidKey> {
+  /// Returns the decoded [`PublicKey`].
+  pub fn decode(&self) -> PublicKey {
+    decode(self.as_did()).expect("valid did:key")
+  }
+}
+
+/// Decodes the [`PublicKey`] from the method-specific-id of a `did:key` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:key` DID.
+pub fn decode(did: &DID) -> Result<PublicKey> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  PublicKey::from_multibase(did.method_id())
+}
+
+/// Creates a `did:key` [`DID`] from the given `key`.
+pub fn encode(key: &PublicKey) -> DID {
+  let mut output: String = String::with_capacity(key.as_bytes().len() * 2 + 8);
+
+  output.push_str(DID::SCHEME);
+  output.push(':');
+  output.push_str(METHOD);
+  output.push(':');
+  output.push_str(&key.to_multibase());
+
+  // base58btc only produces characters valid in a method-specific-id.
+  DID::parse(output).expect("did:key is a valid DID")
+}
+
+/// Returns the default verification method of a `did:key` DID.
+///
+/// This is the DID with its method-specific-id as the fragment, eg.
+/// `did:key:z6Mk…#z6Mk…`.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:key` DID.
+pub fn verification_method(did: &DID) -> Result<DID> {
+  decode(did)?;
+
+  let mut output: DID = did.clone();
+
+  output.set_path("");
+  output.set_query(None);
+  output.set_fragment(Some(did.method_id()));
+
+  Ok(output)
+}
+
+/// Decodes an unsigned LEB128 varint, returning the value and remaining bytes.
+pub(crate) fn varint_decode(data: &[u8]) -> Option<(u64, &[u8])> {
+  let mut value: u64 = 0;
+
+  // Multiformats varints are limited to 9 bytes (63 bits).
+  for (index, byte) in data.iter().take(9).enumerate() {
+    value |= u64::from(byte & 0x7F) << (index * 7);
+
+    if byte & 0x80 == 0 {
+      // Reject non-minimal encodings.
+      if index > 0 && *byte == 0 {
+        return None;
+      }
+
+      return Some((value, &data[index + 1..]));
+    }
+  }
+
+  None
+}
+
+/// Encodes `value` as an unsigned LEB128 varint.
+pub(crate) fn varint_encode(mut value: u64, output: &mut Vec<u8>) {
+  while value >= 0x80 {
+    output.push((value as u8 & 0x7F) | 0x80);
+    value >>= 7;
+  }
+
+  output.push(value as u8);
+}
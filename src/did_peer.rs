@@ -0,0 +1,630 @@
+//! Support for the [did:peer](https://identity.foundation/peer-did-method-spec/) method.
+//!
+//! The first character of a `did:peer` method-specific-id is the numeric
+//! algorithm ("numalgo") used to generate the DID. Numalgo 0 (inception key),
+//! numalgo 2 (multiple inception keys and services), and numalgo 4 (hashed
+//! input document) are supported.
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use serde_json::Map;
+use serde_json::Value;
+use sha2::Digest as _;
+use sha2::Sha256;
+
+use crate::did::DID;
+use crate::did_key::varint_decode;
+use crate::did_key::varint_encode;
+use crate::did_key::Codec;
+use crate::did_key::PublicKey;
+use crate::did_key::BASE58BTC;
+use crate::typed::TypedDid;
+
+/// The name of the did:peer method.
+pub const METHOD: &str = "peer";
+
+/// The multicodec code of JSON encoded numalgo 4 input documents.
+const JSON: u64 = 0x0200;
+
+/// The multihash code of SHA2-256.
+const SHA2_256: u64 = 0x12;
+
+/// The length of a SHA2-256 digest.
+const SHA2_256_LENGTH: usize = 32;
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    HashMismatch => "Hash Mismatch",
+    InvalidDocument => "Invalid Document",
+    InvalidElement => "Invalid Element",
+    InvalidHash => "Invalid Hash",
+    InvalidKey => "Invalid Key",
+    InvalidMethod => "Invalid Method",
+    InvalidNumalgo => "Invalid Numalgo",
+    InvalidService => "Invalid Service",
+    UnsupportedNumalgo => "Unsupported Numalgo",
+  }
+}
+
+/// The numeric algorithm used to generate a `did:peer` DID.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Numalgo {
+  /// A single inception key.
+  Zero,
+  /// A hash of a genesis document.
+  One,
+  /// Multiple inception keys and services.
+  Two,
+  /// A hash of a numalgo 2 DID.
+  Three,
+  /// A hash of an input document, in long or short form.
+  Four,
+}
+
+impl Numalgo {
+  /// Returns the numalgo of the given method-specific-id `prefix` character.
+  pub const fn from_char(value: char) -> Option<Self> {
+    match value {
+      '0' => Some(Self::Zero),
+      '1' => Some(Self::One),
+      '2' => Some(Self::Two),
+      '3' => Some(Self::Three),
+      '4' => Some(Self::Four),
+      _ => None,
+    }
+  }
+
+  /// Returns the method-specific-id prefix character of the numalgo.
+  pub const fn as_char(&self) -> char {
+    match self {
+      Self::Zero => '0',
+      Self::One => '1',
+      Self::Two => '2',
+      Self::Three => '3',
+      Self::Four => '4',
+    }
+  }
+}
+
+/// The verification relationship of a numalgo 2 key.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Purpose {
+  /// `A` - assertionMethod
+  Assertion,
+  /// `E` - keyAgreement
+  Encryption,
+  /// `V` - authentication
+  Verification,
+  /// `I` - capabilityInvocation
+  CapabilityInvocation,
+  /// `D` - capabilityDelegation
+  CapabilityDelegation,
+}
+
+impl Purpose {
+  /// All purposes, in DID document order.
+  pub const ALL: &'static [Self] = &[
+    Self::Verification,
+    Self::Assertion,
+    Self::Encryption,
+    Self::CapabilityInvocation,
+    Self::CapabilityDelegation,
+  ];
+
+  /// Returns the purpose of the given element prefix character.
+  pub const fn from_char(value: char) -> Option<Self> {
+    match value {
+      'A' => Some(Self::Assertion),
+      'E' => Some(Self::Encryption),
+      'V' => Some(Self::Verification),
+      'I' => Some(Self::CapabilityInvocation),
+      'D' => Some(Self::CapabilityDelegation),
+      _ => None,
+    }
+  }
+
+  /// Returns the element prefix character of the purpose.
+  pub const fn as_char(&self) -> char {
+    match self {
+      Self::Assertion => 'A',
+      Self::Encryption => 'E',
+      Self::Verification => 'V',
+      Self::CapabilityInvocation => 'I',
+      Self::CapabilityDelegation => 'D',
+    }
+  }
+
+  /// Returns the name of the DID document verification relationship.
+  pub const fn relationship(&self) -> &'static str {
+    match self {
+      Self::Assertion => "assertionMethod",
+      Self::Encryption => "keyAgreement",
+      Self::Verification => "authentication",
+      Self::CapabilityInvocation => "capabilityInvocation",
+      Self::CapabilityDelegation => "capabilityDelegation",
+    }
+  }
+}
+
+/// An element of a numalgo 2 method-specific-id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Element {
+  /// A key with the given purpose.
+  Key(Purpose, PublicKey),
+  /// A service, with abbreviations expanded.
+  Service(Map<String, Value>),
+}
+
+/// The decoded method-specific-id of a `did:peer` DID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Peer {
+  /// A numalgo 0 inception key.
+  Key(PublicKey),
+  /// The keys and services of a numalgo 2 DID, in order.
+  Elements(Vec<Element>),
+  /// The hash of a numalgo 4 DID and, in long form, the input document.
+  Hash {
+    hash: String,
+    document: Option<Map<String, Value>>,
+  },
+}
+
+/// A verification method of an expanded `did:peer` DID document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationMethod {
+  id: DID,
+  purpose: Purpose,
+  key: PublicKey,
+}
+
+impl VerificationMethod {
+  /// Returns the DID URL of the verification method, eg. `did:peer:2…#key-1`.
+  #[inline]
+  pub const fn id(&self) -> &DID {
+    &self.id
+  }
+
+  /// Returns the verification relationship of the verification method.
+  #[inline]
+  pub const fn purpose(&self) -> Purpose {
+    self.purpose
+  }
+
+  /// Returns the public key of the verification method.
+  #[inline]
+  pub const fn key(&self) -> &PublicKey {
+    &self.key
+  }
+}
+
+/// A service of an expanded `did:peer` DID document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Service {
+  id: DID,
+  properties: Map<String, Value>,
+}
+
+impl Service {
+  /// Returns the DID URL of the service, eg. `did:peer:2…#service`.
+  #[inline]
+  pub const fn id(&self) -> &DID {
+    &self.id
+  }
+
+  /// Returns the service properties, excluding the `id`.
+  #[inline]
+  pub const fn properties(&self) -> &Map<String, Value> {
+    &self.properties
+  }
+}
+
+/// A minimal DID document expanded from a `did:peer` DID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Document {
+  id: DID,
+  verification_methods: Vec<VerificationMethod>,
+  services: Vec<Service>,
+}
+
+impl Document {
+  /// Returns the DID of the document.
+  #[inline]
+  pub const fn id(&self) -> &DID {
+    &self.id
+  }
+
+  /// Returns the verification methods of the document, in order.
+  #[inline]
+  pub fn verification_methods(&self) -> &[VerificationMethod] {
+    &self.verification_methods
+  }
+
+  /// Returns the services of the document, in order.
+  #[inline]
+  pub fn services(&self) -> &[Service] {
+    &self.services
+  }
+
+  /// Returns the JSON representation of the document.
+  ///
+  /// Verification methods are `Multikey`s and ids are relative to the DID.
+  pub fn to_json(&self) -> Value {
+    let mut output: Map<String, Value> = Map::new();
+
+    output.insert("id".into(), self.id.as_str().into());
+
+    output.insert(
+      "verificationMethod".into(),
+      self
+        .verification_methods
+        .iter()
+        .map(|method| {
+          let mut object: Map<String, Value> = Map::new();
+
+          object.insert("id".into(), self.relative(&method.id).into());
+          object.insert("type".into(), "Multikey".into());
+          object.insert("controller".into(), self.id.as_str().into());
+          object.insert(
+            "publicKeyMultibase".into(),
+            method.key.to_multibase().into(),
+          );
+
+          Value::Object(object)
+        })
+        .collect(),
+    );
+
+    for purpose in Purpose::ALL {
+      let methods: Vec<Value> = self
+        .verification_methods
+        .iter()
+        .filter(|method| method.purpose == *purpose)
+        .map(|method| self.relative(&method.id).into())
+        .collect();
+
+      if !methods.is_empty() {
+        output.insert(purpose.relationship().into(), methods.into());
+      }
+    }
+
+    if !self.services.is_empty() {
+      output.insert(
+        "service".into(),
+        self
+          .services
+          .iter()
+          .map(|service| {
+            let mut object: Map<String, Value> = service.properties.clone();
+
+            object.insert("id".into(), self.relative(&service.id).into());
+
+            Value::Object(object)
+          })
+          .collect(),
+      );
+    }
+
+    Value::Object(output)
+  }
+
+  fn relative(&self, id: &DID) -> String {
+    self.id.make_relative(id).unwrap_or_else(|| id.to_string())
+  }
+}
+
+crate::did_method! {
+  /// The did:peer method.
+  pub struct DidPeer = METHOD, |did| decode(did).is_ok();
+}
+
+impl TypedDid<DidPeer> {
+  /// Returns the decoded [`Peer`] DID.
+  pub fn decode(&self) -> Peer {
+    decode(self.as_did()).expect("valid did:peer")
+  }
+}
+
+/// Returns the numalgo of a `did:peer` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a `did:peer` DID with a known numalgo.
+pub fn numalgo(did: &DID) -> Result<Numalgo> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  did
+    .method_id()
+    .chars()
+    .next()
+    .and_then(Numalgo::from_char)
+    .ok_or(Error::InvalidNumalgo)
+}
+
+/// Decodes the method-specific-id of a `did:peer` DID.
+///
+/// Numalgo 4 long form DIDs are verified against their hash.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:peer` DID or uses an
+/// unsupported numalgo.
+pub fn decode(did: &DID) -> Result<Peer> {
+  let numalgo: Numalgo = numalgo(did)?;
+  let data: &str = &did.method_id()[1..];
+
+  match numalgo {
+    Numalgo::Zero => PublicKey::from_multibase(data)
+      .map(Peer::Key)
+      .map_err(|_| Error::InvalidKey),
+    Numalgo::Two => decode_elements(data).map(Peer::Elements),
+    Numalgo::Four => decode_hash(data),
+    Numalgo::One | Numalgo::Three => Err(Error::UnsupportedNumalgo),
+  }
+}
+
+/// Expands a numalgo 0 or numalgo 2 `did:peer` DID into a minimal DID
+/// document.
+///
+/// Numalgo 2 keys are identified as `#key-1`, `#key-2`, … in order, and
+/// services without an explicit id as `#service`, `#service-1`, ….
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid numalgo 0 or numalgo 2 `did:peer` DID.
+pub fn expand(did: &DID) -> Result<Document> {
+  let base: DID = base(did);
+
+  let mut document: Document = Document {
+    id: base.clone(),
+    verification_methods: Vec::new(),
+    services: Vec::new(),
+  };
+
+  match decode(did)? {
+    Peer::Key(key) => {
+      // Key agreement keys cannot be used for authentication.
+      let purpose: Purpose = match key.codec() {
+        Codec::X25519 => Purpose::Encryption,
+        _ => Purpose::Verification,
+      };
+
+      document.verification_methods.push(VerificationMethod {
+        id: with_fragment(&base, &key.to_multibase()),
+        purpose,
+        key,
+      });
+    }
+    Peer::Elements(elements) => {
+      for element in elements {
+        match element {
+          Element::Key(purpose, key) => {
+            let index: usize = document.verification_methods.len() + 1;
+
+            document.verification_methods.push(VerificationMethod {
+              id: with_fragment(&base, &format!("key-{}", index)),
+              purpose,
+              key,
+            });
+          }
+          Element::Service(mut properties) => {
+            let id: DID = match properties.remove("id") {
+              Some(Value::String(id)) => base.join(id).map_err(|_| Error::InvalidService)?,
+              Some(_) => return Err(Error::InvalidService),
+              None => match document.services.len() {
+                0 => with_fragment(&base, "service"),
+                index => with_fragment(&base, &format!("service-{}", index)),
+              },
+            };
+
+            document.services.push(Service { id, properties });
+          }
+        }
+      }
+    }
+    Peer::Hash { .. } => return Err(Error::UnsupportedNumalgo),
+  }
+
+  Ok(document)
+}
+
+/// Returns the short form of a numalgo 4 `did:peer` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid numalgo 4 `did:peer` DID.
+pub fn short_form(did: &DID) -> Result<DID> {
+  match decode(did)? {
+    Peer::Hash { hash, .. } => Ok(peer(Numalgo::Four, &hash)),
+    _ => Err(Error::InvalidNumalgo),
+  }
+}
+
+/// Creates a long form numalgo 4 `did:peer` [`DID`] from the given input
+/// `document`.
+pub fn encode_long_form(document: &Map<String, Value>) -> DID {
+  let json: Vec<u8> = serde_json::to_vec(document).expect("JSON object serializes");
+
+  let mut bytes: Vec<u8> = Vec::with_capacity(json.len() + 2);
+
+  varint_encode(JSON, &mut bytes);
+  bytes.extend_from_slice(&json);
+
+  let encoded: String = multibase(&bytes);
+  let hash: String = multihash(&encoded);
+
+  peer(Numalgo::Four, &format!("{}:{}", hash, encoded))
+}
+
+fn decode_elements(data: &str) -> Result<Vec<Element>> {
+  let mut elements: core::str::Split<'_, char> = data.split('.');
+  let mut output: Vec<Element> = Vec::new();
+
+  // The method-specific-id must begin with `2.`
+  if elements.next() != Some("") {
+    return Err(Error::InvalidElement);
+  }
+
+  for element in elements {
+    let mut chars: core::str::Chars<'_> = element.chars();
+    let prefix: char = chars.next().ok_or(Error::InvalidElement)?;
+    let value: &str = chars.as_str();
+
+    if prefix == 'S' {
+      let bytes: Vec<u8> = URL_SAFE_NO_PAD
+        .decode(value)
+        .map_err(|_| Error::InvalidService)?;
+
+      match serde_json::from_slice(&bytes) {
+        Ok(Value::Object(service)) => output.push(Element::Service(abbreviations(service))),
+        Ok(Value::Array(services)) => {
+          for service in services {
+            match service {
+              Value::Object(service) => output.push(Element::Service(abbreviations(service))),
+              _ => return Err(Error::InvalidService),
+            }
+          }
+        }
+        _ => return Err(Error::InvalidService),
+      }
+    } else {
+      let purpose: Purpose = Purpose::from_char(prefix).ok_or(Error::InvalidElement)?;
+      let key: PublicKey = PublicKey::from_multibase(value).map_err(|_| Error::InvalidKey)?;
+
+      output.push(Element::Key(purpose, key));
+    }
+  }
+
+  if output.is_empty() {
+    return Err(Error::InvalidElement);
+  }
+
+  Ok(output)
+}
+
+fn decode_hash(data: &str) -> Result<Peer> {
+  let (hash, encoded): (&str, Option<&str>) = match data.find(':') {
+    Some(index) => (&data[..index], Some(&data[index + 1..])),
+    None => (data, None),
+  };
+
+  let digest: Vec<u8> = hash
+    .strip_prefix(BASE58BTC)
+    .and_then(|hash| bs58::decode(hash).into_vec().ok())
+    .ok_or(Error::InvalidHash)?;
+
+  match varint_decode(&digest) {
+    Some((SHA2_256, [length, digest @ ..]))
+      if *length as usize == SHA2_256_LENGTH && digest.len() == SHA2_256_LENGTH => {}
+    _ => return Err(Error::InvalidHash),
+  }
+
+  let document: Option<Map<String, Value>> = match encoded {
+    Some(encoded) => {
+      if multihash(encoded) != hash {
+        return Err(Error::HashMismatch);
+      }
+
+      let bytes: Vec<u8> = encoded
+        .strip_prefix(BASE58BTC)
+        .and_then(|encoded| bs58::decode(encoded).into_vec().ok())
+        .ok_or(Error::InvalidDocument)?;
+
+      match varint_decode(&bytes) {
+        Some((JSON, json)) => match serde_json::from_slice(json) {
+          Ok(Value::Object(document)) => Some(document),
+          _ => return Err(Error::InvalidDocument),
+        },
+        _ => return Err(Error::InvalidDocument),
+      }
+    }
+    None => None,
+  };
+
+  Ok(Peer::Hash {
+    hash: hash.to_string(),
+    document,
+  })
+}
+
+/// Expands the abbreviated keys and values of a numalgo 2 service.
+fn abbreviations(service: Map<String, Value>) -> Map<String, Value> {
+  service
+    .into_iter()
+    .map(|(key, value)| {
+      let key: String = match key.as_str() {
+        "t" => "type".into(),
+        "s" => "serviceEndpoint".into(),
+        "r" => "routingKeys".into(),
+        "a" => "accept".into(),
+        _ => key,
+      };
+
+      let value: Value = match (key.as_str(), value) {
+        ("type", Value::String(value)) if value == "dm" => "DIDCommMessaging".into(),
+        (_, Value::Object(value)) => Value::Object(abbreviations(value)),
+        (_, value) => value,
+      };
+
+      (key, value)
+    })
+    .collect()
+}
+
+/// Returns the base58btc multibase encoding of `data`.
+fn multibase(data: &[u8]) -> String {
+  let mut output: String = String::with_capacity(data.len() * 2);
+
+  output.push(BASE58BTC);
+  output.push_str(&bs58::encode(data).into_string());
+  output
+}
+
+/// Returns the base58btc multibase encoding of the SHA2-256 multihash of
+/// `data`.
+fn multihash(data: &str) -> String {
+  let mut bytes: Vec<u8> = Vec::with_capacity(SHA2_256_LENGTH + 2);
+
+  varint_encode(SHA2_256, &mut bytes);
+  varint_encode(SHA2_256_LENGTH as u64, &mut bytes);
+  bytes.extend_from_slice(&Sha256::digest(data.as_bytes()));
+
+  multibase(&bytes)
+}
+
+/// Returns `did` without a path, query, or fragment.
+fn base(did: &DID) -> DID {
+  let mut output: DID = did.clone();
+
+  output.set_path("");
+  output.set_query(None);
+  output.set_fragment(None);
+  output
+}
+
+fn with_fragment(did: &DID, fragment: &str) -> DID {
+  let mut output: DID = did.clone();
+
+  output.set_fragment(Some(fragment));
+  output
+}
+
+fn peer(numalgo: Numalgo, data: &str) -> DID {
+  let mut output: String = String::with_capacity(data.len() + 10);
+
+  output.push_str(DID::SCHEME);
+  output.push(':');
+  output.push_str(METHOD);
+  output.push(':');
+  output.push(numalgo.as_char());
+  output.push_str(data);
+
+  // base58btc only produces characters valid in a method-specific-id.
+  DID::parse(output).expect("did:peer is a valid DID")
+}
@@ -0,0 +1,216 @@
+//! Support for the [did:pkh](https://github.com/w3c-ccg/did-pkh/blob/main/did-pkh-method-draft.md) method.
+//!
+//! A `did:pkh` method-specific-id is a
+//! [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md)
+//! account id: `<namespace>:<reference>:<address>`.
+use alloc::string::String;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::core::Core;
+use crate::did::DID;
+use crate::eip55;
+use crate::typed::TypedDid;
+
+/// The name of the did:pkh method.
+pub const METHOD: &str = "pkh";
+
+/// The CAIP-2 namespace of EVM chains.
+pub const EIP155: &str = "eip155";
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidAddress => "Invalid Address",
+    InvalidChecksum => "Invalid Checksum",
+    InvalidMethod => "Invalid Method",
+    InvalidNamespace => "Invalid Namespace",
+    InvalidReference => "Invalid Reference",
+  }
+}
+
+/// A CAIP-2 blockchain id: `<namespace>:<reference>`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChainId {
+  namespace: String,
+  reference: String,
+}
+
+impl ChainId {
+  /// Creates a new [`ChainId`] from the given `namespace` and `reference`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if either part has an invalid length or character set.
+  pub fn new(namespace: impl Into<String>, reference: impl Into<String>) -> Result<Self> {
+    let namespace: String = namespace.into();
+    let reference: String = reference.into();
+
+    // namespace: [-a-z0-9]{3,8}
+    if !matches!(namespace.len(), 3..=8) || !namespace.bytes().all(char_namespace) {
+      return Err(Error::InvalidNamespace);
+    }
+
+    // reference: [-_a-zA-Z0-9]{1,32}
+    if !matches!(reference.len(), 1..=32) || !reference.bytes().all(char_reference) {
+      return Err(Error::InvalidReference);
+    }
+
+    Ok(Self {
+      namespace,
+      reference,
+    })
+  }
+
+  /// Returns the chain namespace, eg. `eip155`.
+  #[inline]
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns the chain reference, eg. `1`.
+  #[inline]
+  pub fn reference(&self) -> &str {
+    &self.reference
+  }
+}
+
+impl Display for ChainId {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    write!(f, "{}:{}", self.namespace, self.reference)
+  }
+}
+
+/// A CAIP-10 account id: `<namespace>:<reference>:<address>`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AccountId {
+  chain_id: ChainId,
+  address: String,
+}
+
+impl AccountId {
+  /// Creates a new [`AccountId`] from the given `chain_id` and `address`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `address` has an invalid length or character set, or is
+  /// an `eip155` address with an invalid EIP-55 checksum.
+  pub fn new(chain_id: ChainId, address: impl Into<String>) -> Result<Self> {
+    let address: String = address.into();
+
+    // address: [-.%a-zA-Z0-9]{1,128}
+    if !matches!(address.len(), 1..=128) || !address.bytes().all(char_address) {
+      return Err(Error::InvalidAddress);
+    }
+
+    // Ensure any `%` begins a valid percent-encoded octet.
+    if Core::validate_method_id(&address).is_err() {
+      return Err(Error::InvalidAddress);
+    }
+
+    if chain_id.namespace() == EIP155 {
+      if !eip55::is_address(&address) {
+        return Err(Error::InvalidAddress);
+      }
+
+      if !eip55::verify(&address) {
+        return Err(Error::InvalidChecksum);
+      }
+    }
+
+    Ok(Self { chain_id, address })
+  }
+
+  /// Parses an [`AccountId`] from a CAIP-10 string.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not a valid CAIP-10 account id.
+  pub fn parse(data: &str) -> Result<Self> {
+    let mut parts = data.splitn(3, ':');
+
+    let namespace: &str = parts.next().ok_or(Error::InvalidNamespace)?;
+    let reference: &str = parts.next().ok_or(Error::InvalidReference)?;
+    let address: &str = parts.next().ok_or(Error::InvalidAddress)?;
+
+    Self::new(ChainId::new(namespace, reference)?, address)
+  }
+
+  /// Returns the CAIP-2 chain id of the account.
+  #[inline]
+  pub const fn chain_id(&self) -> &ChainId {
+    &self.chain_id
+  }
+
+  /// Returns the account address.
+  #[inline]
+  pub fn address(&self) -> &str {
+    &self.address
+  }
+}
+
+impl Display for AccountId {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    write!(f, "{}:{}", self.chain_id, self.address)
+  }
+}
+
+crate::did_method! {
+  /// The did:pkh method.
+  pub struct DidPkh = METHOD, |did| decode(did).is_ok();
+}
+
+impl TypedDid<DidPkh> {
+  /// Returns the decoded [`AccountId`].
+  pub fn decode(&self) -> AccountId {
+    decode(self.as_did()).expect("valid did:pkh")
+  }
+}
+
+/// Decodes the [`AccountId`] from the method-specific-id of a `did:pkh` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:pkh` DID.
+pub fn decode(did: &DID) -> Result<AccountId> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  AccountId::parse(did.method_id())
+}
+
+/// Creates a `did:pkh` [`DID`] from the given `account`.
+pub fn encode(account: &AccountId) -> DID {
+  let mut output: String = String::with_capacity(64);
+
+  output.push_str(DID::SCHEME);
+  output.push(':');
+  output.push_str(METHOD);
+  output.push(':');
+  output.push_str(account.chain_id().namespace());
+  output.push(':');
+  output.push_str(account.chain_id().reference());
+  output.push(':');
+  output.push_str(account.address());
+
+  // Account ids are validated to contain only method-specific-id characters.
+  DID::parse(output).expect("did:pkh is a valid DID")
+}
+
+#[inline(always)]
+const fn char_namespace(byte: u8) -> bool {
+  matches!(byte, b'-' | b'a'..=b'z' | b'0'..=b'9')
+}
+
+#[inline(always)]
+const fn char_reference(byte: u8) -> bool {
+  matches!(byte, b'-' | b'_' | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9')
+}
+
+#[inline(always)]
+const fn char_address(byte: u8) -> bool {
+  matches!(byte, b'-' | b'.' | b'%' | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9')
+}
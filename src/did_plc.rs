@@ -0,0 +1,192 @@
+//! Support for the [did:plc](https://web.plc.directory/spec/v0.1/did-plc) method.
+//!
+//! A `did:plc` method-specific-id is the first 24 characters of the lowercase
+//! base32 encoding (RFC 4648, no padding) of the SHA2-256 hash of the signed
+//! genesis operation.
+use alloc::string::String;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::did::DID;
+use crate::typed::DidMethod;
+use crate::typed::TypedDid;
+
+/// The name of the did:plc method.
+pub const METHOD: &str = "plc";
+
+/// The length of a `did:plc` method-specific-id.
+pub const LENGTH: usize = 24;
+
+/// The number of hash bytes encoded in a `did:plc` method-specific-id.
+pub const HASH_LENGTH: usize = LENGTH * 5 / 8;
+
+/// The lowercase RFC 4648 base32 alphabet.
+const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidCase => "Invalid Case",
+    InvalidCharacter => "Invalid Character",
+    InvalidHashLength => "Invalid Hash Length",
+    InvalidLength => "Invalid Length",
+    InvalidMethod => "Invalid Method",
+  }
+}
+
+/// Options for did:plc validation.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Options {
+  strict: bool,
+}
+
+impl Options {
+  /// Creates a new set of [`Options`] with strict mode disabled.
+  pub const fn new() -> Self {
+    Self { strict: false }
+  }
+
+  /// Enables or disables strict mode.
+  ///
+  /// When enabled, identifiers containing uppercase characters are rejected.
+  /// Otherwise they are normalized to lowercase.
+  pub const fn strict(mut self, value: bool) -> Self {
+    self.strict = value;
+    self
+  }
+}
+
+/// A validated `did:plc` method-specific-id.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PlcId(String);
+
+impl PlcId {
+  /// Creates a new [`PlcId`] from the given method-specific-id `data`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not 24 base32 characters, or contains
+  /// uppercase characters in strict mode.
+  pub fn new(data: &str, options: Options) -> Result<Self> {
+    if data.len() != LENGTH {
+      return Err(Error::InvalidLength);
+    }
+
+    if !data
+      .bytes()
+      .all(|byte| ALPHABET.contains(&byte.to_ascii_lowercase()))
+    {
+      return Err(Error::InvalidCharacter);
+    }
+
+    if options.strict && data.bytes().any(|byte| byte.is_ascii_uppercase()) {
+      return Err(Error::InvalidCase);
+    }
+
+    Ok(Self(data.to_ascii_lowercase()))
+  }
+
+  /// Creates a new [`PlcId`] from the SHA2-256 `hash` of a signed genesis
+  /// operation.
+  ///
+  /// Only the first 15 bytes of the hash are encoded.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `hash` is shorter than 15 bytes.
+  pub fn from_hash(hash: &[u8]) -> Result<Self> {
+    if hash.len() < HASH_LENGTH {
+      return Err(Error::InvalidHashLength);
+    }
+
+    let mut output: String = String::with_capacity(LENGTH);
+
+    // 15 bytes are exactly three 40-bit groups of eight characters.
+    for chunk in hash[..HASH_LENGTH].chunks(5) {
+      let group: u64 = chunk
+        .iter()
+        .fold(0, |group, byte| (group << 8) | u64::from(*byte));
+
+      for index in (0..8).rev() {
+        output.push(ALPHABET[(group >> (index * 5)) as usize & 0x1F] as char);
+      }
+    }
+
+    Ok(Self(output))
+  }
+
+  /// Returns the method-specific-id as a string slice.
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Display for PlcId {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+impl AsRef<str> for PlcId {
+  #[inline]
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+/// The did:plc method.
+///
+/// Identifiers are case-insensitive and normalized to lowercase.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DidPlc;
+
+impl DidMethod for DidPlc {
+  const NAME: &'static str = METHOD;
+
+  fn validate_method_id(did: &DID) -> bool {
+    decode(did, Options::new()).is_ok()
+  }
+
+  fn normalize(did: &mut DID) {
+    if let Ok(id) = decode(did, Options::new()) {
+      did.set_method_id(id.as_str());
+    }
+  }
+}
+
+impl TypedDid<DidPlc> {
+  /// Returns the decoded [`PlcId`], normalized to lowercase.
+  pub fn decode(&self) -> PlcId {
+    decode(self.as_did(), Options::new()).expect("valid did:plc")
+  }
+}
+
+/// Decodes the [`PlcId`] from the method-specific-id of a `did:plc` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:plc` DID.
+pub fn decode(did: &DID, options: Options) -> Result<PlcId> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  PlcId::new(did.method_id(), options)
+}
+
+/// Creates a `did:plc` [`DID`] from the given `id`.
+pub fn encode(id: &PlcId) -> DID {
+  let mut output: String = String::with_capacity(LENGTH + 8);
+
+  output.push_str(DID::SCHEME);
+  output.push(':');
+  output.push_str(METHOD);
+  output.push(':');
+  output.push_str(id.as_str());
+
+  // base32 only produces characters valid in a method-specific-id.
+  DID::parse(output).expect("did:plc is a valid DID")
+}
@@ -0,0 +1,270 @@
+//! Support for the [did:web](https://w3c-ccg.github.io/did-method-web/) method.
+//!
+//! Provides the transformation from a `did:web` [`DID`] to the HTTPS URL of
+//! its DID document, and the reverse mapping.
+use alloc::string::String;
+use alloc::string::ToString as _;
+use percent_encoding::percent_decode_str;
+use percent_encoding::utf8_percent_encode;
+
+use crate::core::METHOD_ID_ENCODE_SET;
+use crate::did::DID;
+use crate::typed::TypedDid;
+
+/// The name of the did:web method.
+pub const METHOD: &str = "web";
+
+/// The path of the DID document when the DID has no path segments.
+pub const WELL_KNOWN: &str = "/.well-known";
+
+/// The name of the DID document file.
+pub const DOCUMENT: &str = "/did.json";
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidDomain => "Invalid Domain",
+    InvalidMethod => "Invalid Method",
+    InvalidPath => "Invalid Path",
+    InvalidPort => "Invalid Port",
+    InvalidUrl => "Invalid Url",
+    IpLiteral => "IP Literal Host",
+    Localhost => "Localhost Host",
+  }
+}
+
+/// Options for the did:web transformations.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Options {
+  strict: bool,
+}
+
+impl Options {
+  /// Creates a new set of [`Options`] with strict mode disabled.
+  pub const fn new() -> Self {
+    Self { strict: false }
+  }
+
+  /// Enables or disables strict mode.
+  ///
+  /// When enabled, IP-literal and `localhost` hosts are rejected.
+  pub const fn strict(mut self, value: bool) -> Self {
+    self.strict = value;
+    self
+  }
+}
+
+crate::did_method! {
+  /// The did:web method.
+  pub struct DidWeb = METHOD, |did| to_url(did, Options::new()).is_ok();
+}
+
+impl TypedDid<DidWeb> {
+  /// Transforms the DID into the HTTPS URL of its DID document.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the domain is rejected by `options`.
+  pub fn to_url(&self, options: Options) -> Result<String> {
+    to_url(self.as_did(), options)
+  }
+}
+
+/// Transforms a `did:web` [`DID`] into the HTTPS URL of its DID document.
+///
+/// Only the method-specific-id is considered; the path, query, and fragment
+/// of a DID URL are ignored.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:web` DID.
+pub fn to_url(did: &DID, options: Options) -> Result<String> {
+  if did.method() != METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  https_url(did.method_id_segments(), options, DOCUMENT)
+}
+
+/// Transforms the `:`-separated domain and path `segments` of a did:web style
+/// method-specific-id into an HTTPS URL of the given `document`.
+pub(crate) fn https_url<'a>(
+  mut segments: impl Iterator<Item = &'a str>,
+  options: Options,
+  document: &str,
+) -> Result<String> {
+  let authority: String = segments
+    .next()
+    .map(percent_decode_str)
+    .and_then(|authority| authority.decode_utf8().ok())
+    .ok_or(Error::InvalidDomain)?
+    .into_owned();
+
+  let (host, port): (&str, Option<&str>) = split_port(&authority)?;
+  let host: String = to_ascii(host, options)?;
+
+  let mut output: String = String::with_capacity(authority.len() + 32);
+
+  output.push_str("https://");
+  output.push_str(&host);
+
+  if let Some(port) = port {
+    output.push(':');
+    output.push_str(port);
+  }
+
+  let mut path: bool = false;
+
+  for segment in segments {
+    if segment.is_empty() || is_dot_segment(segment) {
+      return Err(Error::InvalidPath);
+    }
+
+    output.push('/');
+    output.push_str(segment);
+    path = true;
+  }
+
+  if !path {
+    output.push_str(WELL_KNOWN);
+  }
+
+  output.push_str(document);
+
+  Ok(output)
+}
+
+/// Transforms an HTTPS URL into a `did:web` [`DID`].
+///
+/// The URL may refer to either the DID document (`…/did.json`) or the
+/// directory containing it. A document at the root of the domain is not a
+/// did:web document location; use `/.well-known/did.json` instead.
+///
+/// # Errors
+///
+/// Returns `Err` if `url` is not a valid HTTPS URL without a query or
+/// fragment.
+pub fn from_url(url: &str, options: Options) -> Result<DID> {
+  let url: &str = strip_prefix_ignore_case(url, "https://").ok_or(Error::InvalidUrl)?;
+
+  if url.contains(['?', '#']) {
+    return Err(Error::InvalidUrl);
+  }
+
+  let (authority, path): (&str, &str) = match url.find('/') {
+    Some(index) => url.split_at(index),
+    None => (url, ""),
+  };
+
+  if authority.contains('@') {
+    return Err(Error::InvalidUrl);
+  }
+
+  if authority.starts_with('[') {
+    return Err(Error::IpLiteral);
+  }
+
+  let (host, port): (&str, Option<&str>) = split_port(authority)?;
+  let host: String = to_ascii(host, options)?;
+
+  let mut output: String = String::with_capacity(url.len() + 16);
+
+  output.push_str(DID::SCHEME);
+  output.push(':');
+  output.push_str(METHOD);
+  output.push(':');
+  output.push_str(&host);
+
+  if let Some(port) = port {
+    output.push_str("%3A");
+    output.push_str(port);
+  }
+
+  let path: &str = match path.strip_suffix(DOCUMENT) {
+    Some("") => return Err(Error::InvalidPath),
+    Some(path) => path,
+    None => path.strip_suffix('/').unwrap_or(path),
+  };
+
+  if path != WELL_KNOWN {
+    for segment in path.split('/').skip(1) {
+      let segment: String = percent_decode_str(segment)
+        .decode_utf8()
+        .map_err(|_| Error::InvalidPath)?
+        .to_string();
+
+      if segment.is_empty() || segment == "." || segment == ".." {
+        return Err(Error::InvalidPath);
+      }
+
+      output.push(':');
+      output.extend(utf8_percent_encode(&segment, METHOD_ID_ENCODE_SET));
+    }
+  }
+
+  DID::parse(output).map_err(|_| Error::InvalidUrl)
+}
+
+/// Returns `true` if the percent-encoded path `segment` is `.` or `..`.
+fn is_dot_segment(segment: &str) -> bool {
+  matches!(
+    percent_decode_str(segment).decode_utf8().as_deref(),
+    Ok(".") | Ok("..")
+  )
+}
+
+fn split_port(authority: &str) -> Result<(&str, Option<&str>)> {
+  let (host, port): (&str, Option<&str>) = match authority.rfind(':') {
+    Some(index) => (&authority[..index], Some(&authority[index + 1..])),
+    None => (authority, None),
+  };
+
+  if let Some(port) = port {
+    if port.is_empty() || !port.bytes().all(|byte| byte.is_ascii_digit()) {
+      return Err(Error::InvalidPort);
+    }
+
+    if !matches!(port.parse::<u16>(), Ok(1..=u16::MAX)) {
+      return Err(Error::InvalidPort);
+    }
+  }
+
+  Ok((host, port))
+}
+
+fn to_ascii(host: &str, options: Options) -> Result<String> {
+  if host.is_empty() {
+    return Err(Error::InvalidDomain);
+  }
+
+  let host: String = idna::domain_to_ascii_strict(host).map_err(|_| Error::InvalidDomain)?;
+
+  if options.strict {
+    if is_ipv4(&host) {
+      return Err(Error::IpLiteral);
+    }
+
+    if host == "localhost" || host.ends_with(".localhost") {
+      return Err(Error::Localhost);
+    }
+  }
+
+  Ok(host)
+}
+
+fn is_ipv4(host: &str) -> bool {
+  host.split('.').count() == 4 && host.split('.').all(|octet| octet.parse::<u8>().is_ok())
+}
+
+fn strip_prefix_ignore_case<'a>(data: &'a str, prefix: &str) -> Option<&'a str> {
+  if data.len() >= prefix.len() && data.is_char_boundary(prefix.len()) {
+    let (head, tail): (&str, &str) = data.split_at(prefix.len());
+
+    if head.eq_ignore_ascii_case(prefix) {
+      return Some(tail);
+    }
+  }
+
+  None
+}
@@ -0,0 +1,206 @@
+//! Support for the [did:webvh](https://identity.foundation/didwebvh/) method,
+//! formerly did:tdw.
+//!
+//! A `did:webvh` method-specific-id is a self-certifying identifier (SCID)
+//! followed by a did:web style domain and optional path:
+//! `<SCID>:<domain>[:<path>…]`.
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::did::DID;
+use crate::did_web;
+use crate::did_web::Options;
+use crate::typed::TypedDid;
+
+/// The name of the did:webvh method.
+pub const METHOD: &str = "webvh";
+
+/// The name of the did:tdw method, the predecessor of did:webvh.
+pub const LEGACY_METHOD: &str = "tdw";
+
+/// The name of the DID log file.
+pub const LOG: &str = "/did.jsonl";
+
+/// The multihash code of SHA2-256.
+const SHA2_256: u8 = 0x12;
+
+/// The length of a SHA2-256 digest.
+const SHA2_256_LENGTH: u8 = 32;
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    InvalidMethod => "Invalid Method",
+    InvalidScid => "Invalid SCID",
+    ScidMismatch => "SCID Mismatch",
+    #[from]
+    Web(did_web::Error),
+  }
+}
+
+/// A self-certifying identifier: the base58btc encoded SHA2-256 multihash of
+/// the initial log entry.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Scid(String);
+
+impl Scid {
+  /// Creates a new [`Scid`] from the given encoded `data`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `data` is not a base58btc encoded SHA2-256 multihash.
+  pub fn new(data: &str) -> Result<Self> {
+    digest(data)?;
+
+    Ok(Self(data.into()))
+  }
+
+  /// Creates a new [`Scid`] from the SHA2-256 `hash` of the initial log
+  /// entry.
+  pub fn from_hash(hash: &[u8; 32]) -> Self {
+    let mut bytes: Vec<u8> = Vec::with_capacity(SHA2_256_LENGTH as usize + 2);
+
+    bytes.push(SHA2_256);
+    bytes.push(SHA2_256_LENGTH);
+    bytes.extend_from_slice(hash);
+
+    Self(bs58::encode(bytes).into_string())
+  }
+
+  /// Returns `true` if the SCID was derived from the given SHA2-256 `hash` of
+  /// the initial log entry.
+  pub fn matches(&self, hash: &[u8]) -> bool {
+    match digest(&self.0) {
+      Ok(digest) => digest == hash,
+      Err(_) => false,
+    }
+  }
+
+  /// Returns the SCID as a string slice.
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Display for Scid {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+impl AsRef<str> for Scid {
+  #[inline]
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+crate::did_method! {
+  /// The did:webvh method.
+  pub struct DidWebVh = METHOD, |did| to_url(did, Options::new()).is_ok();
+}
+
+impl TypedDid<DidWebVh> {
+  /// Returns the decoded [`Scid`].
+  pub fn decode(&self) -> Scid {
+    decode(self.as_did()).expect("valid did:webvh")
+  }
+
+  /// Transforms the DID into the HTTPS URL of its DID log.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the domain is rejected by `options`.
+  pub fn to_url(&self, options: Options) -> Result<String> {
+    to_url(self.as_did(), options)
+  }
+}
+
+/// Decodes the [`Scid`] from the method-specific-id of a `did:webvh` or
+/// `did:tdw` DID.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:webvh` or `did:tdw` DID.
+pub fn decode(did: &DID) -> Result<Scid> {
+  if did.method() != METHOD && did.method() != LEGACY_METHOD {
+    return Err(Error::InvalidMethod);
+  }
+
+  let mut segments = did.method_id_segments();
+  let scid: Scid = segments.next().map(Scid::new).ok_or(Error::InvalidScid)??;
+
+  if segments.next().is_none() {
+    return Err(did_web::Error::InvalidDomain.into());
+  }
+
+  Ok(scid)
+}
+
+/// Transforms a `did:webvh` [`DID`] into the HTTPS URL of its DID log.
+///
+/// The domain, port, and path are decoded the same way as `did:web`.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:webvh` DID.
+pub fn to_url(did: &DID, options: Options) -> Result<String> {
+  decode(did)?;
+
+  did_web::https_url(did.method_id_segments().skip(1), options, LOG).map_err(Into::into)
+}
+
+/// Returns the parallel `did:web` [`DID`] of a `did:webvh` DID, eg.
+/// `did:webvh:<SCID>:example.com` → `did:web:example.com`.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:webvh` DID.
+pub fn to_did_web(did: &DID) -> Result<DID> {
+  let scid: Scid = decode(did)?;
+
+  let mut output: DID = did.clone();
+
+  output.set_method(did_web::METHOD);
+  output.set_method_id(&did.method_id()[scid.as_str().len() + 1..]);
+
+  Ok(output)
+}
+
+/// Verifies that the SCID of `did` matches the given SHA2-256 `hash` of the
+/// initial log entry.
+///
+/// # Errors
+///
+/// Returns `Err` if `did` is not a valid `did:webvh` DID, or the SCID does not
+/// match `hash`.
+pub fn verify(did: &DID, hash: &[u8]) -> Result<()> {
+  if decode(did)?.matches(hash) {
+    Ok(())
+  } else {
+    Err(Error::ScidMismatch)
+  }
+}
+
+/// Returns the digest of an encoded SHA2-256 multihash.
+fn digest(data: &str) -> Result<Vec<u8>> {
+  let mut bytes: Vec<u8> = bs58::decode(data)
+    .into_vec()
+    .map_err(|_| Error::InvalidScid)?;
+
+  if bytes.len() != SHA2_256_LENGTH as usize + 2
+    || bytes[0] != SHA2_256
+    || bytes[1] != SHA2_256_LENGTH
+  {
+    return Err(Error::InvalidScid);
+  }
+
+  bytes.drain(..2);
+
+  Ok(bytes)
+}
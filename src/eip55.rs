@@ -0,0 +1,58 @@
+//! Mixed-case checksum address encoding for Ethereum addresses.
+//!
+//! [More Info](https://eips.ethereum.org/EIPS/eip-55)
+use alloc::string::String;
+use tiny_keccak::Hasher as _;
+use tiny_keccak::Keccak;
+
+/// Returns `true` if `address` is a `0x`-prefixed 20-byte hex address.
+pub(crate) fn is_address(address: &str) -> bool {
+  match address.strip_prefix("0x") {
+    Some(hex) => hex.len() == 40 && hex.bytes().all(|byte| byte.is_ascii_hexdigit()),
+    None => false,
+  }
+}
+
+/// Returns the checksum encoding of a `0x`-prefixed 20-byte hex `address`.
+pub(crate) fn checksum(address: &str) -> String {
+  let hex: String = address[2..].to_ascii_lowercase();
+
+  let mut hash: [u8; 32] = [0; 32];
+  let mut keccak: Keccak = Keccak::v256();
+
+  keccak.update(hex.as_bytes());
+  keccak.finalize(&mut hash);
+
+  let mut output: String = String::with_capacity(42);
+
+  output.push_str("0x");
+
+  for (index, ch) in hex.chars().enumerate() {
+    let nibble: u8 = (hash[index / 2] >> (4 * (1 - index % 2))) & 0x0F;
+
+    if nibble >= 8 {
+      output.push(ch.to_ascii_uppercase());
+    } else {
+      output.push(ch);
+    }
+  }
+
+  output
+}
+
+/// Returns `true` if the `0x`-prefixed 20-byte hex `address` has a valid
+/// checksum.
+///
+/// All-lowercase and all-uppercase addresses carry no checksum and are always
+/// considered valid.
+pub(crate) fn verify(address: &str) -> bool {
+  let hex: &str = &address[2..];
+
+  if !hex.bytes().any(|byte| byte.is_ascii_lowercase())
+    || !hex.bytes().any(|byte| byte.is_ascii_uppercase())
+  {
+    return true;
+  }
+
+  checksum(address) == address
+}
@@ -1,41 +1,63 @@
-use core::fmt::Debug;
-use core::fmt::Display;
-use core::fmt::Formatter;
-use core::fmt::Result as FmtResult;
-
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[non_exhaustive]
-pub enum Error {
-  InvalidAuthority,
-  InvalidFragment,
-  InvalidMethodId,
-  InvalidMethodName,
-  InvalidPath,
-  InvalidQuery,
-  InvalidScheme,
-}
+/// Declares an `Error` enum with `as_str`, `Display`, and `std::error::Error`
+/// implementations.
+///
+/// Variants marked `#[from]` wrap the error of another module, delegate
+/// `as_str` to it, and implement `From` for it.
+macro_rules! error_enum {
+  (
+    pub enum Error {
+      $($variant:ident => $message:literal,)*
+      $(#[from] $source:ident($type:ty),)*
+    }
+  ) => {
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    #[non_exhaustive]
+    pub enum Error {
+      $($variant,)*
+      $($source($type),)*
+    }
 
-impl Error {
-  pub const fn as_str(&self) -> &'static str {
-    match self {
-      Self::InvalidAuthority => "Invalid Authority",
-      Self::InvalidFragment => "Invalid Fragment",
-      Self::InvalidMethodId => "Invalid Method Id",
-      Self::InvalidMethodName => "Invalid Method Name",
-      Self::InvalidPath => "Invalid Path",
-      Self::InvalidQuery => "Invalid Query",
-      Self::InvalidScheme => "Invalid Scheme",
+    impl Error {
+      pub const fn as_str(&self) -> &'static str {
+        match self {
+          $(Self::$variant => $message,)*
+          $(Self::$source(error) => error.as_str(),)*
+        }
+      }
     }
-  }
+
+    impl ::core::fmt::Display for Error {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        f.write_str(self.as_str())
+      }
+    }
+
+    $(
+      impl From<$type> for Error {
+        fn from(other: $type) -> Self {
+          Self::$source(other)
+        }
+      }
+    )*
+
+    #[cfg(feature = "std")]
+    impl ::std::error::Error for Error {}
+  };
 }
 
-impl Display for Error {
-  fn fmt(&self, f: &mut Formatter) -> FmtResult {
-    f.write_str(self.as_str())
+#[allow(unused_imports)]
+pub(crate) use error_enum;
+
+error_enum! {
+  pub enum Error {
+    InvalidAuthority => "Invalid Authority",
+    InvalidFragment => "Invalid Fragment",
+    InvalidMethodId => "Invalid Method Id",
+    InvalidMethodName => "Invalid Method Name",
+    InvalidPath => "Invalid Path",
+    InvalidQuery => "Invalid Query",
+    InvalidScheme => "Invalid Scheme",
   }
 }
-
-#[cfg(feature = "std")]
-impl ::std::error::Error for Error {}
@@ -9,11 +9,18 @@ pub struct Input<'a> {
 impl<'a> Input<'a> {
   pub fn new(data: &'a str) -> Self {
     Self {
-      data: data.trim_matches(Self::ctrl_or_space).as_bytes(),
+      data: Self::trim(data).as_bytes(),
       next: 0,
     }
   }
 
+  /// Removes leading and trailing C0 control and space characters.
+  ///
+  /// Component offsets are relative to the trimmed input.
+  pub fn trim(data: &str) -> &str {
+    data.trim_matches(Self::ctrl_or_space)
+  }
+
   #[inline]
   pub const fn index(&self) -> u32 {
     self.next as u32
@@ -45,6 +52,18 @@ impl<'a> Input<'a> {
       })
   }
 
+  /// Consumes a percent-encoded octet (`"%" HEXDIG HEXDIG`), returning `false`
+  /// and leaving the input untouched if one is not next.
+  pub fn pct_encoded(&mut self) -> bool {
+    match self.data.get(self.next..self.next + 3) {
+      Some([b'%', a, b]) if a.is_ascii_hexdigit() && b.is_ascii_hexdigit() => {
+        self.next += 3;
+        true
+      }
+      _ => false,
+    }
+  }
+
   const fn ctrl_or_space(ch: char) -> bool {
     ch.is_ascii_control() || ch.is_ascii_whitespace()
   }
@@ -0,0 +1,142 @@
+//! JSON Canonicalization Scheme (JCS).
+//!
+//! [More Info](https://www.rfc-editor.org/rfc/rfc8785)
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write as _;
+use serde_json::Number;
+use serde_json::Value;
+
+/// Serializes `value` in canonical form.
+///
+/// Object members are sorted by the UTF-16 code units of their names, and
+/// numbers are serialized as ECMAScript does for IEEE 754 doubles.
+pub(crate) fn to_string(value: &Value) -> String {
+  let mut output: String = String::new();
+
+  write_value(value, &mut output);
+
+  output
+}
+
+fn write_value(value: &Value, output: &mut String) {
+  match value {
+    Value::Null => output.push_str("null"),
+    Value::Bool(true) => output.push_str("true"),
+    Value::Bool(false) => output.push_str("false"),
+    Value::Number(number) => write_number(number, output),
+    Value::String(string) => write_string(string, output),
+    Value::Array(array) => {
+      output.push('[');
+
+      for (index, value) in array.iter().enumerate() {
+        if index > 0 {
+          output.push(',');
+        }
+
+        write_value(value, output);
+      }
+
+      output.push(']');
+    }
+    Value::Object(object) => {
+      let mut members: Vec<(&String, &Value)> = object.iter().collect();
+
+      members.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+      output.push('{');
+
+      for (index, (key, value)) in members.into_iter().enumerate() {
+        if index > 0 {
+          output.push(',');
+        }
+
+        write_string(key, output);
+        output.push(':');
+        write_value(value, output);
+      }
+
+      output.push('}');
+    }
+  }
+}
+
+/// [More Info](https://www.rfc-editor.org/rfc/rfc8785#section-3.2.2.2)
+fn write_string(string: &str, output: &mut String) {
+  output.push('"');
+
+  for ch in string.chars() {
+    match ch {
+      '"' => output.push_str("\\\""),
+      '\\' => output.push_str("\\\\"),
+      '\u{8}' => output.push_str("\\b"),
+      '\u{c}' => output.push_str("\\f"),
+      '\n' => output.push_str("\\n"),
+      '\r' => output.push_str("\\r"),
+      '\t' => output.push_str("\\t"),
+      ch if ch < ' ' => {
+        let _ = write!(output, "\\u{:04x}", ch as u32);
+      }
+      ch => output.push(ch),
+    }
+  }
+
+  output.push('"');
+}
+
+/// Serializes `number` with the ECMAScript `Number.prototype.toString`
+/// algorithm.
+///
+/// [More Info](https://www.rfc-editor.org/rfc/rfc8785#section-3.2.2.3)
+fn write_number(number: &Number, output: &mut String) {
+  // JSON numbers are IEEE 754 doubles in JCS; integers beyond 2^53 lose
+  // precision exactly as they would in ECMAScript.
+  let value: f64 = number.as_f64().unwrap_or_default();
+
+  if value == 0.0 {
+    output.push('0');
+    return;
+  }
+
+  if value < 0.0 {
+    output.push('-');
+  }
+
+  // `{:e}` yields the shortest digits that round-trip, eg. `1.2345e-7`.
+  let scientific: String = format!("{:e}", value.abs());
+  let (mantissa, exponent): (&str, &str) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+  let digits: String = mantissa.replace('.', "");
+  let exponent: i32 = exponent.parse().unwrap_or_default();
+
+  // The decimal point position relative to `digits`, ie. value = 0.digits * 10^n.
+  let k: i32 = digits.len() as i32;
+  let n: i32 = exponent + 1;
+
+  if k <= n && n <= 21 {
+    output.push_str(&digits);
+    output.extend((0..n - k).map(|_| '0'));
+  } else if 0 < n && n <= 21 {
+    output.push_str(&digits[..n as usize]);
+    output.push('.');
+    output.push_str(&digits[n as usize..]);
+  } else if -6 < n && n <= 0 {
+    output.push_str("0.");
+    output.extend((0..-n).map(|_| '0'));
+    output.push_str(&digits);
+  } else {
+    output.push_str(&digits[..1]);
+
+    if k > 1 {
+      output.push('.');
+      output.push_str(&digits[1..]);
+    }
+
+    let _ = write!(
+      output,
+      "e{}{}",
+      if n > 0 { "+" } else { "-" },
+      (n - 1).abs()
+    );
+  }
+}
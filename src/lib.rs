@@ -20,14 +20,60 @@ extern crate std;
 #[macro_use]
 extern crate serde;
 
+mod convert;
 mod core;
+mod decoded;
 mod did;
+#[cfg(any(feature = "did-ethr", feature = "did-pkh"))]
+mod eip55;
 mod error;
 mod input;
+#[cfg(any(feature = "did-ion", feature = "did-jwk"))]
+mod jcs;
+mod normalized;
+mod query;
+mod registry;
+mod relative;
+mod typed;
 
+#[cfg(feature = "did-dht")]
+pub mod did_dht;
+#[cfg(feature = "did-ethr")]
+pub mod did_ethr;
+#[cfg(feature = "did-indy")]
+pub mod did_indy;
+#[cfg(feature = "did-ion")]
+pub mod did_ion;
+#[cfg(feature = "did-jwk")]
+pub mod did_jwk;
+#[cfg(feature = "did-key")]
+pub mod did_key;
+#[cfg(feature = "did-peer")]
+pub mod did_peer;
+#[cfg(feature = "did-pkh")]
+pub mod did_pkh;
+#[cfg(feature = "did-plc")]
+pub mod did_plc;
+#[cfg(feature = "did-web")]
+pub mod did_web;
+#[cfg(feature = "did-webvh")]
+pub mod did_webvh;
+#[cfg(feature = "spec-registry")]
+pub mod spec_registry;
+
+pub use self::decoded::Decoded;
 pub use self::did::DID;
 pub use self::error::Error;
 pub use self::error::Result;
+pub use self::normalized::NormalizedDid;
+pub use self::query::QueryPairs;
+pub use self::query::RawQueryPairs;
+pub use self::registry::MethodError;
+pub use self::registry::MethodRegistry;
+pub use self::registry::MethodSpec;
+pub use self::relative::RelativeDid;
+pub use self::typed::DidMethod;
+pub use self::typed::TypedDid;
 
 /// A helper macro to assist with the construction of [`DID`]s.
 #[macro_export]
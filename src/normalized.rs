@@ -0,0 +1,74 @@
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::ops::Deref;
+
+use crate::did::DID;
+
+/// A [`DID`] in normalized form.
+///
+/// Equality, hashing, and ordering are based on the normalized serialization,
+/// so semantically equivalent DIDs such as `did:example:a%3a` and
+/// `did:example:a%3A` compare equal. See [`DID::normalize`].
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct NormalizedDid(DID);
+
+impl NormalizedDid {
+  /// Creates a new [`NormalizedDid`] by normalizing `did`.
+  pub fn new(mut did: DID) -> Self {
+    did.normalize();
+    Self(did)
+  }
+
+  /// Returns a reference to the normalized [`DID`].
+  #[inline]
+  pub const fn as_did(&self) -> &DID {
+    &self.0
+  }
+
+  /// Consumes the [`NormalizedDid`] and returns the normalized [`DID`].
+  #[inline]
+  pub fn into_did(self) -> DID {
+    self.0
+  }
+}
+
+impl Deref for NormalizedDid {
+  type Target = DID;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl AsRef<DID> for NormalizedDid {
+  fn as_ref(&self) -> &DID {
+    &self.0
+  }
+}
+
+impl From<DID> for NormalizedDid {
+  fn from(other: DID) -> Self {
+    Self::new(other)
+  }
+}
+
+impl From<NormalizedDid> for DID {
+  fn from(other: NormalizedDid) -> Self {
+    other.into_did()
+  }
+}
+
+impl Debug for NormalizedDid {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    Debug::fmt(&self.0, f)
+  }
+}
+
+impl Display for NormalizedDid {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    Display::fmt(&self.0, f)
+  }
+}
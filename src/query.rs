@@ -0,0 +1,88 @@
+use alloc::borrow::Cow;
+use core::iter::FusedIterator;
+use percent_encoding::percent_decode_str;
+
+/// An iterator of raw (key, value) pairs in a DID query.
+///
+/// Pairs are separated by `&` and keys are separated from values by the first
+/// `=`. Keys and values are returned as they appear in the query, so no
+/// information is lost; see [`QueryPairs`] for decoded pairs.
+#[derive(Clone, Debug)]
+pub struct RawQueryPairs<'a> {
+  input: &'a str,
+}
+
+impl<'a> RawQueryPairs<'a> {
+  pub(crate) const fn new(input: &'a str) -> Self {
+    Self { input }
+  }
+}
+
+impl<'a> Iterator for RawQueryPairs<'a> {
+  type Item = (&'a str, &'a str);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if self.input.is_empty() {
+        return None;
+      }
+
+      let (pair, rest): (&str, &str) = self.input.split_once('&').unwrap_or((self.input, ""));
+
+      self.input = rest;
+
+      if pair.is_empty() {
+        continue;
+      }
+
+      return Some(pair.split_once('=').unwrap_or((pair, "")));
+    }
+  }
+}
+
+impl FusedIterator for RawQueryPairs<'_> {}
+
+/// An iterator of percent-decoded (key, value) pairs in a DID query.
+///
+/// Pairs are split as by [`RawQueryPairs`]. Unlike
+/// `application/x-www-form-urlencoded` parsing, `+` is preserved as a literal
+/// character, as required by
+/// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-3.4).
+///
+/// Decoding is lossy: octets that are not valid UTF-8 (eg. `%FF`) are
+/// replaced with `U+FFFD`. Use [`RawQueryPairs`] to recover them.
+#[derive(Clone, Debug)]
+pub struct QueryPairs<'a> {
+  inner: RawQueryPairs<'a>,
+}
+
+impl<'a> QueryPairs<'a> {
+  pub(crate) const fn new(input: &'a str) -> Self {
+    Self {
+      inner: RawQueryPairs::new(input),
+    }
+  }
+
+  /// Returns an iterator over the remaining pairs without decoding them.
+  #[inline]
+  pub fn raw(self) -> RawQueryPairs<'a> {
+    self.inner
+  }
+}
+
+impl<'a> Iterator for QueryPairs<'a> {
+  type Item = (Cow<'a, str>, Cow<'a, str>);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self
+      .inner
+      .next()
+      .map(|(key, value)| (decode(key), decode(value)))
+  }
+}
+
+impl FusedIterator for QueryPairs<'_> {}
+
+fn decode(input: &str) -> Cow<'_, str> {
+  percent_decode_str(input).decode_utf8_lossy()
+}
@@ -0,0 +1,245 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::typed::DidMethod;
+
+/// Method-specific syntax rules for a DID method.
+///
+/// Validators are applied after a [`DID`] has been parsed with the generic
+/// DID grammar. Specs must be `Send + Sync` so a [`MethodRegistry`] can be
+/// shared between threads.
+pub trait MethodSpec: Send + Sync {
+  /// Returns the name of the DID method, eg. `key`.
+  fn name(&self) -> &str;
+
+  /// Returns `true` if the method-specific-id of `did` is valid.
+  fn validate_method_id(&self, did: &DID) -> bool;
+
+  /// Returns `true` if the path of `did` is valid.
+  ///
+  /// Any path is accepted by default.
+  fn validate_path(&self, did: &DID) -> bool {
+    let _ = did;
+    true
+  }
+
+  /// Returns `true` if the query of `did` is valid.
+  ///
+  /// Any query is accepted by default.
+  fn validate_query(&self, did: &DID) -> bool {
+    let _ = did;
+    true
+  }
+
+  /// Normalizes a valid `did` into its canonical method-specific form.
+  ///
+  /// The DID is left unchanged by default.
+  fn normalize(&self, did: &mut DID) {
+    let _ = did;
+  }
+}
+
+impl<M> MethodSpec for M
+where
+  M: DidMethod + Send + Sync,
+{
+  fn name(&self) -> &str {
+    M::NAME
+  }
+
+  fn validate_method_id(&self, did: &DID) -> bool {
+    <M as DidMethod>::validate_method_id(did)
+  }
+
+  fn normalize(&self, did: &mut DID) {
+    <M as DidMethod>::normalize(did)
+  }
+}
+
+/// An error returned when a [`DID`] fails validation against a
+/// [`MethodRegistry`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MethodError {
+  method: Option<String>,
+  error: Error,
+}
+
+impl MethodError {
+  /// Returns the name of the method that rejected the DID, if the DID was
+  /// syntactically valid.
+  #[inline]
+  pub fn method(&self) -> Option<&str> {
+    self.method.as_deref()
+  }
+
+  /// Returns the kind of error.
+  #[inline]
+  pub const fn error(&self) -> Error {
+    self.error
+  }
+}
+
+impl Display for MethodError {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    match self.method.as_deref() {
+      Some(method) => write!(f, "{} (did:{})", self.error, method),
+      None => Display::fmt(&self.error, f),
+    }
+  }
+}
+
+impl From<Error> for MethodError {
+  fn from(other: Error) -> Self {
+    Self {
+      method: None,
+      error: other,
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for MethodError {}
+
+/// A collection of [`MethodSpec`]s keyed by method name.
+pub struct MethodRegistry {
+  specs: BTreeMap<String, Box<dyn MethodSpec>>,
+  allow_unknown: bool,
+}
+
+impl MethodRegistry {
+  /// Creates a new, empty [`MethodRegistry`].
+  ///
+  /// Methods without a registered spec are validated by the generic DID
+  /// grammar only.
+  pub fn new() -> Self {
+    Self {
+      specs: BTreeMap::new(),
+      allow_unknown: true,
+    }
+  }
+
+  /// Creates a new [`MethodRegistry`] with the [`DidMethod`] marker of every
+  /// DID method enabled by crate features.
+  pub fn builtin() -> Self {
+    #[allow(unused_mut)]
+    let mut this: Self = Self::new();
+
+    #[cfg(feature = "did-dht")]
+    this.register(crate::did_dht::DidDht);
+    #[cfg(feature = "did-ethr")]
+    this.register(crate::did_ethr::DidEthr);
+    #[cfg(feature = "did-indy")]
+    this.register(crate::did_indy::DidIndy);
+    #[cfg(feature = "did-indy")]
+    this.register(crate::did_indy::DidSov);
+    #[cfg(feature = "did-ion")]
+    this.register(crate::did_ion::DidIon);
+    #[cfg(feature = "did-jwk")]
+    this.register(crate::did_jwk::DidJwk);
+    #[cfg(feature = "did-key")]
+    this.register(crate::did_key::DidKey);
+    #[cfg(feature = "did-peer")]
+    this.register(crate::did_peer::DidPeer);
+    #[cfg(feature = "did-pkh")]
+    this.register(crate::did_pkh::DidPkh);
+    #[cfg(feature = "did-plc")]
+    this.register(crate::did_plc::DidPlc);
+    #[cfg(feature = "did-web")]
+    this.register(crate::did_web::DidWeb);
+    #[cfg(feature = "did-webvh")]
+    this.register(crate::did_webvh::DidWebVh);
+
+    this
+  }
+
+  /// Sets whether methods without a registered spec are accepted.
+  ///
+  /// When disabled, DIDs with an unknown method are rejected with
+  /// [`Error::InvalidMethodName`].
+  pub fn allow_unknown(mut self, value: bool) -> Self {
+    self.allow_unknown = value;
+    self
+  }
+
+  /// Registers `spec`, replacing any existing spec for the same method.
+  pub fn register(&mut self, spec: impl MethodSpec + 'static) {
+    self.specs.insert(spec.name().into(), Box::new(spec));
+  }
+
+  /// Returns the spec registered for `method`, if any.
+  pub fn get(&self, method: &str) -> Option<&dyn MethodSpec> {
+    self.specs.get(method).map(|spec| &**spec)
+  }
+
+  /// Returns `true` if a spec is registered for `method`.
+  pub fn contains(&self, method: &str) -> bool {
+    self.specs.contains_key(method)
+  }
+
+  /// Returns an iterator over the registered method names.
+  pub fn methods(&self) -> impl Iterator<Item = &str> + '_ {
+    self.specs.keys().map(String::as_str)
+  }
+
+  /// Validates `did` against the spec registered for its method.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `did` is rejected by its method spec, or its method is
+  /// unknown and unknown methods are not allowed.
+  pub fn validate(&self, did: &DID) -> Result<(), MethodError> {
+    let error = |error: Error| MethodError {
+      method: Some(did.method().into()),
+      error,
+    };
+
+    let spec: &dyn MethodSpec = match self.get(did.method()) {
+      Some(spec) => spec,
+      None if self.allow_unknown => return Ok(()),
+      None => return Err(error(Error::InvalidMethodName)),
+    };
+
+    if !spec.validate_method_id(did) {
+      return Err(error(Error::InvalidMethodId));
+    }
+
+    if !spec.validate_path(did) {
+      return Err(error(Error::InvalidPath));
+    }
+
+    if !spec.validate_query(did) {
+      return Err(error(Error::InvalidQuery));
+    }
+
+    Ok(())
+  }
+
+  /// Normalizes `did` with the spec registered for its method, if any.
+  pub fn normalize(&self, did: &mut DID) {
+    if let Some(spec) = self.specs.get(did.method()) {
+      spec.normalize(did);
+    }
+  }
+}
+
+impl Default for MethodRegistry {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Debug for MethodRegistry {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.debug_struct("MethodRegistry")
+      .field("methods", &self.specs.keys())
+      .field("allow_unknown", &self.allow_unknown)
+      .finish()
+  }
+}
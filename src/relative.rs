@@ -0,0 +1,161 @@
+use alloc::string::String;
+use alloc::string::ToString as _;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::str::FromStr;
+
+use crate::core::Core;
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::input::Input;
+
+/// A relative DID URL reference, eg. `/path?query#fragment`.
+///
+/// Relative references are resolved against a base [`DID`](crate::DID) with
+/// [`DID::join`](crate::DID::join).
+#[derive(Clone)]
+pub struct RelativeDid {
+  data: String,
+  core: Core,
+}
+
+impl RelativeDid {
+  /// Parses a [`RelativeDid`] from the provided `input`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the path, query, or fragment is invalid, the first
+  /// segment of a relative path contains a colon, or a network-path
+  /// reference (`//...`) does not name a valid DID.
+  pub fn parse(input: impl AsRef<str>) -> Result<Self> {
+    let data: &str = Input::trim(input.as_ref());
+
+    // Network-path references are resolved by `DID::join` as `did:...`.
+    if let Some(authority) = data.strip_prefix("//") {
+      Core::parse([DID::SCHEME, ":", authority].concat())?;
+    }
+
+    Ok(Self {
+      core: Core::parse_relative(data)?,
+      data: data.to_string(),
+    })
+  }
+
+  /// Returns the serialized [`RelativeDid`].
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    &self.data
+  }
+
+  /// Consumes the [`RelativeDid`] and returns the serialization.
+  #[inline]
+  pub fn into_string(self) -> String {
+    self.data
+  }
+
+  /// Returns the [`RelativeDid`] path.
+  #[inline]
+  pub fn path(&self) -> &str {
+    self.core.path(self.as_str())
+  }
+
+  /// Returns the [`RelativeDid`] query, if any.
+  #[inline]
+  pub fn query(&self) -> Option<&str> {
+    self.core.query(self.as_str())
+  }
+
+  /// Returns the [`RelativeDid`] fragment, if any.
+  #[inline]
+  pub fn fragment(&self) -> Option<&str> {
+    self.core.fragment(self.as_str())
+  }
+}
+
+impl Hash for RelativeDid {
+  fn hash<H>(&self, hasher: &mut H)
+  where
+    H: Hasher,
+  {
+    self.as_str().hash(hasher)
+  }
+}
+
+impl PartialEq for RelativeDid {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+impl Eq for RelativeDid {}
+
+impl PartialOrd for RelativeDid {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for RelativeDid {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.as_str().cmp(other.as_str())
+  }
+}
+
+impl PartialEq<str> for RelativeDid {
+  fn eq(&self, other: &str) -> bool {
+    self.as_str() == other
+  }
+}
+
+impl PartialEq<&'_ str> for RelativeDid {
+  fn eq(&self, other: &&'_ str) -> bool {
+    self == *other
+  }
+}
+
+impl Debug for RelativeDid {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    Debug::fmt(self.as_str(), f)
+  }
+}
+
+impl Display for RelativeDid {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+impl AsRef<str> for RelativeDid {
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl FromStr for RelativeDid {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+impl TryFrom<String> for RelativeDid {
+  type Error = Error;
+
+  fn try_from(other: String) -> Result<Self, Self::Error> {
+    Self::parse(other)
+  }
+}
+
+impl From<RelativeDid> for String {
+  fn from(other: RelativeDid) -> Self {
+    other.into_string()
+  }
+}
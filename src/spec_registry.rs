@@ -0,0 +1,288 @@
+//! Support for the method list of the
+//! [DID Specification Registries](https://www.w3.org/TR/did-spec-registries/#did-methods).
+//!
+//! No snapshot is shipped with the crate: callers supply the method entries
+//! published in the `methods/` directory of the registries repository, either
+//! as a JSON array or one entry at a time, so the data is as current as they
+//! choose to keep it.
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::core::Core;
+use crate::did::DID;
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+crate::error::error_enum! {
+  pub enum Error {
+    DuplicateMethod => "Duplicate Method",
+    InvalidEntry => "Invalid Entry",
+    InvalidJson => "Invalid Json",
+    InvalidMethodName => "Invalid Method Name",
+    InvalidStatus => "Invalid Status",
+  }
+}
+
+/// The registration status of a DID method.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+  Registered,
+  Deprecated,
+}
+
+impl Status {
+  /// Returns the status as it appears in the registry.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Registered => "registered",
+      Self::Deprecated => "deprecated",
+    }
+  }
+}
+
+impl Display for Status {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+/// The contact details of a DID method entry.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Contact {
+  name: Option<String>,
+  email: Option<String>,
+  website: Option<String>,
+}
+
+impl Contact {
+  /// Returns the name of the contact, if any.
+  #[inline]
+  pub fn name(&self) -> Option<&str> {
+    self.name.as_deref()
+  }
+
+  /// Returns the email of the contact, if any.
+  #[inline]
+  pub fn email(&self) -> Option<&str> {
+    self.email.as_deref()
+  }
+
+  /// Returns the website of the contact, if any.
+  #[inline]
+  pub fn website(&self) -> Option<&str> {
+    self.website.as_deref()
+  }
+}
+
+/// A DID method entry of the registry.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MethodEntry {
+  name: String,
+  status: Status,
+  specification: String,
+  contact: Contact,
+  verifiable_data_registry: Option<String>,
+}
+
+impl MethodEntry {
+  /// Returns the method name, eg. `web`.
+  #[inline]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the registration status of the method.
+  #[inline]
+  pub const fn status(&self) -> Status {
+    self.status
+  }
+
+  /// Returns the URL of the method specification.
+  #[inline]
+  pub fn specification(&self) -> &str {
+    &self.specification
+  }
+
+  /// Returns the contact details of the method.
+  #[inline]
+  pub const fn contact(&self) -> &Contact {
+    &self.contact
+  }
+
+  /// Returns the verifiable data registry of the method, if any.
+  #[inline]
+  pub fn verifiable_data_registry(&self) -> Option<&str> {
+    self.verifiable_data_registry.as_deref()
+  }
+
+  fn from_json(mut object: Map<String, Value>) -> Result<Self> {
+    let name: String = string(&mut object, "name").ok_or(Error::InvalidEntry)?;
+
+    if Core::validate_method(&name).is_err() {
+      return Err(Error::InvalidMethodName);
+    }
+
+    let status: Status = match string(&mut object, "status").as_deref() {
+      Some("registered") => Status::Registered,
+      Some("deprecated") => Status::Deprecated,
+      Some(_) => return Err(Error::InvalidStatus),
+      None => return Err(Error::InvalidEntry),
+    };
+
+    let specification: String = string(&mut object, "specification").ok_or(Error::InvalidEntry)?;
+
+    let contact: Contact = Contact {
+      name: string(&mut object, "contactName"),
+      email: string(&mut object, "contactEmail"),
+      website: string(&mut object, "contactWebsite"),
+    };
+
+    Ok(Self {
+      name,
+      status,
+      specification,
+      contact,
+      verifiable_data_registry: string(&mut object, "verifiableDataRegistry"),
+    })
+  }
+}
+
+/// The result of checking the method of a [`DID`] against the registry.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Check<'a> {
+  /// The method is registered.
+  Registered(&'a MethodEntry),
+  /// The method is registered, but deprecated.
+  Deprecated(&'a MethodEntry),
+  /// The method is not in the registry.
+  Unregistered,
+}
+
+/// A queryable set of DID methods from the DID Specification
+/// Registries.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpecRegistry {
+  entries: BTreeMap<String, MethodEntry>,
+}
+
+impl SpecRegistry {
+  /// Creates a new, empty [`SpecRegistry`].
+  pub fn new() -> Self {
+    Self {
+      entries: BTreeMap::new(),
+    }
+  }
+
+  /// Loads a registry from a JSON array of method entries.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `json` is not an array of valid method entries, or
+  /// contains the same method more than once.
+  pub fn from_json(json: &str) -> Result<Self> {
+    Self::from_slice(json.as_bytes())
+  }
+
+  /// Loads a registry from a UTF-8 encoded JSON array of method entries.
+  ///
+  /// # Errors
+  ///
+  /// See [`SpecRegistry::from_json`].
+  pub fn from_slice(json: &[u8]) -> Result<Self> {
+    let entries: Vec<Value> = match serde_json::from_slice(json) {
+      Ok(Value::Array(entries)) => entries,
+      Ok(_) => return Err(Error::InvalidEntry),
+      Err(_) => return Err(Error::InvalidJson),
+    };
+
+    let mut this: Self = Self::new();
+
+    for entry in entries {
+      this.insert(entry)?;
+    }
+
+    Ok(this)
+  }
+
+  /// Adds a single method entry, eg. the contents of one file of the
+  /// `methods/` directory of the registries repository.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `json` is not a valid method entry, or the method is
+  /// already in the registry.
+  pub fn insert_json(&mut self, json: &str) -> Result<()> {
+    match serde_json::from_str(json) {
+      Ok(entry) => self.insert(entry),
+      Err(_) => Err(Error::InvalidJson),
+    }
+  }
+
+  /// Returns the entry of the given `method`, if any.
+  pub fn get(&self, method: &str) -> Option<&MethodEntry> {
+    self.entries.get(method)
+  }
+
+  /// Returns `true` if the given `method` is in the registry.
+  pub fn contains(&self, method: &str) -> bool {
+    self.entries.contains_key(method)
+  }
+
+  /// Returns the number of entries in the registry.
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  /// Returns `true` if the registry has no entries.
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  /// Returns an iterator over the entries of the registry, ordered by name.
+  pub fn iter(&self) -> impl Iterator<Item = &MethodEntry> + '_ {
+    self.entries.values()
+  }
+
+  fn insert(&mut self, entry: Value) -> Result<()> {
+    let entry: MethodEntry = match entry {
+      Value::Object(object) => MethodEntry::from_json(object)?,
+      _ => return Err(Error::InvalidEntry),
+    };
+
+    if self.entries.contains_key(entry.name()) {
+      return Err(Error::DuplicateMethod);
+    }
+
+    self.entries.insert(entry.name.clone(), entry);
+
+    Ok(())
+  }
+
+  /// Checks the method of `did` against the registry.
+  pub fn check(&self, did: &DID) -> Check<'_> {
+    self.check_method(did.method())
+  }
+
+  /// Checks the given `method` name against the registry.
+  pub fn check_method(&self, method: &str) -> Check<'_> {
+    match self.get(method) {
+      Some(entry) if entry.status == Status::Deprecated => Check::Deprecated(entry),
+      Some(entry) => Check::Registered(entry),
+      None => Check::Unregistered,
+    }
+  }
+}
+
+/// Removes the non-empty string member `key` from `object`.
+fn string(object: &mut Map<String, Value>, key: &str) -> Option<String> {
+  match object.remove(key) {
+    Some(Value::String(value)) if !value.is_empty() => Some(value),
+    _ => None,
+  }
+}
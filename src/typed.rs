@@ -0,0 +1,282 @@
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::str::FromStr;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A DID method known at compile time.
+///
+/// Use the [`did_method!`](crate::did_method) macro to declare a marker type
+/// for a custom method. Every marker is also a
+/// [`MethodSpec`](crate::MethodSpec), so it can be added to a
+/// [`MethodRegistry`](crate::MethodRegistry).
+pub trait DidMethod {
+  /// The name of the DID method, eg. `web`.
+  const NAME: &'static str;
+
+  /// Returns `true` if the method-specific-id of `did` is valid.
+  ///
+  /// The built-in markers only accept DIDs their module can decode, so the
+  /// `decode` accessors of [`TypedDid`] never fail.
+  fn validate_method_id(did: &DID) -> bool;
+
+  /// Normalizes a valid `did` into its canonical method-specific form.
+  ///
+  /// The DID is left unchanged by default.
+  fn normalize(did: &mut DID) {
+    let _ = did;
+  }
+}
+
+/// A [`DID`] statically known to use the DID method `M`.
+pub struct TypedDid<M: DidMethod> {
+  did: DID,
+  marker: PhantomData<fn() -> M>,
+}
+
+impl<M: DidMethod> TypedDid<M> {
+  /// Parses a [`TypedDid`] from the provided `input`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if any DID segments are invalid, or the DID does not use
+  /// the method `M`.
+  pub fn parse(input: impl AsRef<str>) -> Result<Self> {
+    DID::parse(input).and_then(Self::from_did)
+  }
+
+  /// Creates a new [`TypedDid`] from the given `did`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `did` does not use the method `M`, or its
+  /// method-specific-id is rejected by `M`.
+  ///
+  /// The DID is normalized with [`DidMethod::normalize`].
+  pub fn from_did(mut did: DID) -> Result<Self> {
+    if did.method() != M::NAME {
+      return Err(Error::InvalidMethodName);
+    }
+
+    if !M::validate_method_id(&did) {
+      return Err(Error::InvalidMethodId);
+    }
+
+    M::normalize(&mut did);
+
+    Ok(Self {
+      did,
+      marker: PhantomData,
+    })
+  }
+
+  /// Returns a reference to the untyped [`DID`].
+  #[inline]
+  pub const fn as_did(&self) -> &DID {
+    &self.did
+  }
+
+  /// Consumes the [`TypedDid`] and returns the untyped [`DID`].
+  #[inline]
+  pub fn into_did(self) -> DID {
+    self.did
+  }
+}
+
+impl<M: DidMethod> Deref for TypedDid<M> {
+  type Target = DID;
+
+  #[inline]
+  fn deref(&self) -> &Self::Target {
+    &self.did
+  }
+}
+
+impl<M: DidMethod> Clone for TypedDid<M> {
+  fn clone(&self) -> Self {
+    Self {
+      did: self.did.clone(),
+      marker: PhantomData,
+    }
+  }
+}
+
+impl<M: DidMethod> Hash for TypedDid<M> {
+  fn hash<H>(&self, hasher: &mut H)
+  where
+    H: Hasher,
+  {
+    self.did.hash(hasher)
+  }
+}
+
+impl<M: DidMethod> PartialEq for TypedDid<M> {
+  fn eq(&self, other: &Self) -> bool {
+    self.did == other.did
+  }
+}
+
+impl<M: DidMethod> Eq for TypedDid<M> {}
+
+impl<M: DidMethod> PartialOrd for TypedDid<M> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<M: DidMethod> Ord for TypedDid<M> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.did.cmp(&other.did)
+  }
+}
+
+impl<M: DidMethod> PartialEq<DID> for TypedDid<M> {
+  fn eq(&self, other: &DID) -> bool {
+    self.did == *other
+  }
+}
+
+impl<M: DidMethod> PartialEq<str> for TypedDid<M> {
+  fn eq(&self, other: &str) -> bool {
+    self.did == *other
+  }
+}
+
+impl<M: DidMethod> PartialEq<&'_ str> for TypedDid<M> {
+  fn eq(&self, other: &&'_ str) -> bool {
+    self.did == *other
+  }
+}
+
+impl<M: DidMethod> Debug for TypedDid<M> {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    Debug::fmt(&self.did, f)
+  }
+}
+
+impl<M: DidMethod> Display for TypedDid<M> {
+  fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    Display::fmt(&self.did, f)
+  }
+}
+
+impl<M: DidMethod> AsRef<str> for TypedDid<M> {
+  fn as_ref(&self) -> &str {
+    self.did.as_str()
+  }
+}
+
+impl<M: DidMethod> AsRef<DID> for TypedDid<M> {
+  fn as_ref(&self) -> &DID {
+    &self.did
+  }
+}
+
+impl<M: DidMethod> FromStr for TypedDid<M> {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+impl<M: DidMethod> TryFrom<DID> for TypedDid<M> {
+  type Error = Error;
+
+  fn try_from(other: DID) -> Result<Self, Self::Error> {
+    Self::from_did(other)
+  }
+}
+
+impl<M: DidMethod> TryFrom<String> for TypedDid<M> {
+  type Error = Error;
+
+  fn try_from(other: String) -> Result<Self, Self::Error> {
+    Self::parse(other)
+  }
+}
+
+impl<M: DidMethod> From<TypedDid<M>> for DID {
+  fn from(other: TypedDid<M>) -> Self {
+    other.into_did()
+  }
+}
+
+impl<M: DidMethod> From<TypedDid<M>> for String {
+  fn from(other: TypedDid<M>) -> Self {
+    other.into_did().into_string()
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<M: DidMethod> ::serde::Serialize for TypedDid<M> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: ::serde::Serializer,
+  {
+    serializer.serialize_str(self.did.as_str())
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, M: DidMethod> ::serde::Deserialize<'de> for TypedDid<M> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: ::serde::Deserializer<'de>,
+  {
+    let data: String = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+
+    Self::parse(data).map_err(::serde::de::Error::custom)
+  }
+}
+
+/// Declares a marker type implementing [`DidMethod`].
+///
+/// The method-specific-id is validated by the optional closure-like
+/// expression, and accepted as-is otherwise.
+///
+/// ```
+/// use did_url::did_method;
+/// use did_url::TypedDid;
+///
+/// did_method! {
+///   /// The did:example method.
+///   pub struct Example = "example", |did| did.method_id().len() == 3;
+/// }
+///
+/// assert!(TypedDid::<Example>::parse("did:example:123").is_ok());
+/// assert!(TypedDid::<Example>::parse("did:example:1234").is_err());
+/// assert!(TypedDid::<Example>::parse("did:other:123").is_err());
+/// ```
+#[macro_export]
+macro_rules! did_method {
+  ($(#[$meta:meta])* $vis:vis struct $ident:ident = $name:expr $(;)?) => {
+    $crate::did_method! {
+      $(#[$meta])* $vis struct $ident = $name, |_did| true;
+    }
+  };
+  ($(#[$meta:meta])* $vis:vis struct $ident:ident = $name:expr, |$did:ident| $expr:expr $(;)?) => {
+    $(#[$meta])*
+    #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    $vis struct $ident;
+
+    impl $crate::DidMethod for $ident {
+      const NAME: &'static str = $name;
+
+      fn validate_method_id($did: &$crate::DID) -> bool {
+        $expr
+      }
+    }
+  };
+}
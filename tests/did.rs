@@ -1,4 +1,5 @@
 use did_url::*;
+use std::borrow::Cow;
 
 #[test]
 fn test_method() -> Result<()> {
@@ -174,12 +175,10 @@ fn test_fragment() -> Result<()> {
 fn test_relative() -> Result<()> {
   let did: DID = did!("did:example:123/a/b/c/d?q");
 
-  // assert_eq!(did.join("g:h")?, "g:h");
   assert_eq!(did.join("g")?, "did:example:123/a/b/c/g");
   assert_eq!(did.join("./g")?, "did:example:123/a/b/c/g");
   assert_eq!(did.join("g/")?, "did:example:123/a/b/c/g/");
   // assert_eq!(did.join("/g")?, "did:example:123/a/g");
-  // assert_eq!(did.join("//g")?, "did:example:123/g");
   assert_eq!(did.join("?y")?, "did:example:123/a/b/c/d?y");
   assert_eq!(did.join("g?y")?, "did:example:123/a/b/c/g?y");
   assert_eq!(did.join("#s")?, "did:example:123/a/b/c/d?q#s");
@@ -200,3 +199,311 @@ fn test_relative() -> Result<()> {
 
   Ok(())
 }
+
+#[test]
+fn test_try_set() -> Result<()> {
+  let mut did: DID = did!("did:test:123/a/b/c?foo=bar#frag");
+
+  assert_eq!(did.try_set_method("UPPER"), Err(Error::InvalidMethodName));
+  assert_eq!(did.try_set_method(""), Err(Error::InvalidMethodName));
+  assert_eq!(did.try_set_method_id(""), Err(Error::InvalidMethodId));
+  assert_eq!(did.try_set_method_id("a/b"), Err(Error::InvalidMethodId));
+  assert_eq!(
+    did.try_set_path("no-leading-slash"),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(did.try_set_path("/a b"), Err(Error::InvalidPath));
+  assert_eq!(did.try_set_query(Some("a#b")), Err(Error::InvalidQuery));
+  assert_eq!(
+    did.try_set_fragment(Some("a#b")),
+    Err(Error::InvalidFragment)
+  );
+  assert_eq!(did.as_str(), "did:test:123/a/b/c?foo=bar#frag");
+
+  did.try_set_method("foo")?;
+  did.try_set_method_id("456:789")?;
+  did.try_set_path("/foo")?;
+  did.try_set_query(Some("a=b?c"))?;
+  did.try_set_fragment(Some("key-1"))?;
+  assert_eq!(did.as_str(), "did:foo:456:789/foo?a=b?c#key-1");
+  assert_eq!(did, DID::parse(did.as_str())?);
+
+  did.try_set_path("")?;
+  did.try_set_query(None)?;
+  did.try_set_fragment(None)?;
+  assert_eq!(did.as_str(), "did:foo:456:789");
+
+  Ok(())
+}
+
+#[test]
+fn test_decoded() -> Result<()> {
+  let did: DID = did!("did:web:example.com%3A3000/a%20b?q=%2B#key%C3%A9");
+  assert_eq!(did.method_id_decoded(), "example.com:3000");
+  assert_eq!(did.path_decoded().into_str().unwrap(), "/a b");
+  assert_eq!(did.query_decoded().unwrap().as_str(), Some("q=+"));
+  assert_eq!(
+    did.fragment_decoded().unwrap().into_str_lossy(),
+    "key\u{e9}"
+  );
+
+  let did: DID = did!("did:example:123/plain");
+  assert!(matches!(
+    did.path_decoded().into_str(),
+    Ok(Cow::Borrowed("/plain"))
+  ));
+
+  let did: DID = did!("did:example:123#%FF");
+  assert!(did.fragment_decoded().unwrap().as_str().is_none());
+  assert_eq!(did.fragment_decoded().unwrap().as_bytes(), [0xFF]);
+  assert_eq!(
+    did.fragment_decoded().unwrap().into_str().unwrap_err(),
+    Cow::<[u8]>::Owned(vec![0xFF])
+  );
+  assert_eq!(did.fragment_decoded().unwrap().into_str_lossy(), "\u{FFFD}");
+
+  let did: DID = did!("did:example:123");
+  assert!(did.query_decoded().is_none());
+  assert!(did.fragment_decoded().is_none());
+
+  Ok(())
+}
+
+#[test]
+fn test_method_id_segments() -> Result<()> {
+  let did: DID = did!("did:ethr:rsk:testnet:0xb9c5714089478a327f09197987f16f9e5d936e8a/path");
+  let segments: Vec<&str> = did.method_id_segments().collect();
+
+  assert_eq!(
+    segments,
+    [
+      "rsk",
+      "testnet",
+      "0xb9c5714089478a327f09197987f16f9e5d936e8a"
+    ]
+  );
+
+  let did: DID = did!("did:example:123");
+  assert_eq!(did.method_id_segments().collect::<Vec<_>>(), ["123"]);
+
+  Ok(())
+}
+
+#[test]
+fn test_encoded() -> Result<()> {
+  let mut did: DID = did!("did:web:example.com");
+
+  did.push_method_id_segment_encoded("user:1 ü")?;
+  assert_eq!(did.method_id(), "example.com:user%3A1%20%C3%BC");
+
+  assert_eq!(
+    did.push_method_id_segment_encoded(""),
+    Err(Error::InvalidMethodId)
+  );
+  assert_eq!(did.method_id(), "example.com:user%3A1%20%C3%BC");
+
+  did.set_path_encoded("a b/c%d?");
+  assert_eq!(did.path(), "/a%20b/c%25d%3F");
+  assert_eq!(did.path_decoded(), "/a b/c%d?");
+
+  did.set_path_encoded("/x");
+  assert_eq!(did.path(), "/x");
+
+  did.set_fragment_encoded(Some("My Key #1 ✓?"));
+  assert_eq!(did.fragment(), Some("My%20Key%20%231%20%E2%9C%93?"));
+  assert_eq!(did.fragment_decoded().unwrap(), "My Key #1 ✓?");
+
+  assert_eq!(did, DID::parse(did.as_str())?);
+
+  did.set_fragment_encoded(None);
+  did.set_path_encoded("");
+  assert_eq!(did.as_str(), "did:web:example.com:user%3A1%20%C3%BC");
+
+  Ok(())
+}
+
+#[test]
+fn test_query_pairs() -> Result<()> {
+  let did: DID = did!("did:example:123?initial-state=abc+def&a=%2B%20b&&flag&=x");
+
+  let pairs: Vec<(String, String)> = did
+    .query_pairs()
+    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+    .collect();
+
+  assert_eq!(
+    pairs,
+    [
+      ("initial-state".into(), "abc+def".into()),
+      ("a".into(), "+ b".into()),
+      ("flag".into(), "".into()),
+      ("".into(), "x".into()),
+    ]
+  );
+
+  let (_, value) = did.form_query_pairs().next().unwrap();
+  assert_eq!(value, "abc def");
+
+  Ok(())
+}
+
+#[test]
+fn test_raw_query_pairs() -> Result<()> {
+  let did: DID = did!("did:example:123?state=%FF%FE&a=%2B%20b&&flag&=x");
+
+  let pairs: Vec<(&str, &str)> = did.raw_query_pairs().collect();
+
+  assert_eq!(
+    pairs,
+    [
+      ("state", "%FF%FE"),
+      ("a", "%2B%20b"),
+      ("flag", ""),
+      ("", "x")
+    ]
+  );
+
+  // The decoded pairs are lossy for invalid UTF-8.
+  let (_, value) = did.query_pairs().next().unwrap();
+  assert_eq!(value, "\u{fffd}\u{fffd}");
+
+  let mut pairs = did.query_pairs();
+  pairs.next();
+  assert_eq!(pairs.raw().next(), Some(("a", "%2B%20b")));
+
+  Ok(())
+}
+
+#[test]
+fn test_set_query_pairs() -> Result<()> {
+  let mut did: DID = did!("did:example:123#frag");
+
+  did.set_query_pairs(vec![("initial-state", "abc+def/="), ("a b", "x&y")]);
+  assert_eq!(
+    did.as_str(),
+    "did:example:123?initial-state=abc+def/%3D&a%20b=x%26y#frag"
+  );
+  assert_eq!(did, DID::parse(did.as_str())?);
+
+  let pairs: Vec<_> = did.query_pairs().collect();
+  assert_eq!(pairs[0], ("initial-state".into(), "abc+def/=".into()));
+  assert_eq!(pairs[1], ("a b".into(), "x&y".into()));
+
+  did.set_query_pairs(Vec::<(&str, &str)>::new());
+  assert_eq!(did.as_str(), "did:example:123#frag");
+
+  Ok(())
+}
+
+#[test]
+fn test_normalize() -> Result<()> {
+  let mut did: DID = did!("did:example:a%3a%2d/b/./c/../%7e%2f?#");
+  did.normalize();
+  assert_eq!(did.as_str(), "did:example:a%3A-/b/~%2F");
+  assert_eq!(did.path(), "/b/~%2F");
+  assert_eq!(did.query(), None);
+  assert_eq!(did.fragment(), None);
+
+  // `~` is not a valid method-id character
+  let mut did: DID = did!("did:example:a%7e?q=%41#f%2e");
+  did.normalize();
+  assert_eq!(did.as_str(), "did:example:a%7E?q=A#f.");
+
+  Ok(())
+}
+
+#[test]
+fn test_normalized_did() -> Result<()> {
+  use std::collections::HashSet;
+
+  let a: NormalizedDid = NormalizedDid::new(did!("did:example:a%3a"));
+  let b: NormalizedDid = NormalizedDid::new(did!("did:example:a%3A"));
+  let c: NormalizedDid = NormalizedDid::new(did!("did:example:a?"));
+  let d: NormalizedDid = NormalizedDid::new(did!("did:example:a"));
+
+  assert_eq!(a, b);
+  assert_eq!(c, d);
+  assert_ne!(a, d);
+  assert_eq!(c.method_id(), "a");
+
+  let set: HashSet<NormalizedDid> = vec![a, b, c, d].into_iter().collect();
+  assert_eq!(set.len(), 2);
+
+  Ok(())
+}
+
+#[test]
+fn test_make_relative() -> Result<()> {
+  let base: DID = did!("did:example:123/a/b/c/d?q");
+
+  let cases: &[(&str, &str)] = &[
+    ("did:example:123/a/b/c/d?q", ""),
+    ("did:example:123/a/b/c/d?q#key-1", "#key-1"),
+    ("did:example:123/a/b/c/d?versionId=2", "?versionId=2"),
+    ("did:example:123/a/b/c/d", "d"),
+    ("did:example:123/a/b/c/g", "g"),
+    ("did:example:123/a/b/c/g/", "g/"),
+    ("did:example:123/a/b/c/", "."),
+    ("did:example:123/a/b/g?y#s", "../g?y#s"),
+    ("did:example:123/a/b/other", "../other"),
+    ("did:example:123/a/other", "/a/other"),
+    ("did:example:123/x", "/x"),
+    ("did:example:123/a/b/c/k:1", "./k:1"),
+    ("did:example:123/a/b/c//x", ".//x"),
+    ("did:example:123/a/b/c//", ".//"),
+    ("did:example:123/a/b//x", "..//x"),
+  ];
+
+  for (target, expected) in cases {
+    let target: DID = DID::parse(target)?;
+    let relative: String = base.make_relative(&target).unwrap();
+
+    assert_eq!(relative, *expected, "{}", target);
+    assert_eq!(base.join(&relative)?, target, "{}", relative);
+  }
+
+  let base: DID = did!("did:example:123#key-1");
+  assert_eq!(
+    base.make_relative(&did!("did:example:123#key-2")).unwrap(),
+    "#key-2"
+  );
+  assert_eq!(
+    base
+      .make_relative(&did!("did:example:123?versionId=2"))
+      .unwrap(),
+    "?versionId=2"
+  );
+
+  assert_eq!(
+    base.make_relative(&did!("did:example:123/a/b")).unwrap(),
+    "a/b"
+  );
+  assert_eq!(base.join("a/b")?, "did:example:123/a/b");
+
+  let base: DID = did!("did:example:123/x");
+  let target: DID = did!("did:example:123//y");
+  assert_eq!(base.make_relative(&target).unwrap(), ".//y");
+  assert_eq!(base.join(base.make_relative(&target).unwrap())?, target);
+
+  let base: DID = did!("did:example:123/a//b/c");
+  for target in &[
+    "did:example:123/a/x",
+    "did:example:123/a//x",
+    "did:example:123//x",
+  ] {
+    let target: DID = DID::parse(target)?;
+    let relative: String = base.make_relative(&target).unwrap();
+
+    assert_eq!(base.join(&relative)?, target, "{}", relative);
+  }
+
+  let base: DID = did!("did:example:123?q");
+  let target: DID = did!("did:example:123");
+  assert_eq!(base.make_relative(&target).unwrap(), "did:example:123");
+  assert_eq!(base.join(base.make_relative(&target).unwrap())?, target);
+
+  assert_eq!(base.make_relative(&did!("did:example:456#key-1")), None);
+  assert_eq!(base.make_relative(&did!("did:other:123#key-1")), None);
+
+  Ok(())
+}
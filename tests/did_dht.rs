@@ -0,0 +1,89 @@
+#![cfg(feature = "did-dht")]
+
+use did_url::did;
+use did_url::did_dht;
+use did_url::did_dht::Error;
+use did_url::DID;
+
+#[test]
+fn test_decode() {
+  let did: DID = did!("did:dht:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6y");
+  let key: [u8; 32] = did_dht::decode(&did).unwrap();
+
+  assert_eq!(did_dht::encode(&key), did);
+}
+
+#[test]
+fn test_encode() {
+  let mut key: [u8; 32] = [0; 32];
+
+  for (index, byte) in key.iter_mut().enumerate() {
+    *byte = index as u8;
+  }
+
+  let did: DID = did_dht::encode(&key);
+
+  assert_eq!(
+    did,
+    "did:dht:yyyoryarywdyqnyjbefoadeqbhebnrounoktcfaadrpbs8y7daxo"
+  );
+  assert_eq!(did_dht::decode(&did).unwrap(), key);
+
+  let did: DID = did_dht::encode(&[0xFF; 32]);
+
+  assert_eq!(
+    did,
+    "did:dht:999999999999999999999999999999999999999999999999999o"
+  );
+  assert_eq!(did_dht::decode(&did).unwrap(), [0xFF; 32]);
+}
+
+#[test]
+fn test_verification_method() {
+  let did: DID = did!("did:dht:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6y/path?q#frag");
+
+  assert_eq!(
+    did_dht::verification_method(&did).unwrap(),
+    "did:dht:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6y#0"
+  );
+}
+
+#[test]
+fn test_invalid() {
+  assert_eq!(
+    did_dht::decode(&did!(
+      "did:key:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6y"
+    )),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_dht::decode(&did!(
+      "did:dht:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6"
+    )),
+    Err(Error::InvalidLength)
+  );
+  assert_eq!(
+    did_dht::decode(&did!(
+      "did:dht:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6yy"
+    )),
+    Err(Error::InvalidLength)
+  );
+  assert_eq!(
+    did_dht::decode(&did!(
+      "did:dht:I9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6y"
+    )),
+    Err(Error::InvalidCharacter)
+  );
+  assert_eq!(
+    did_dht::decode(&did!(
+      "did:dht:v9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6y"
+    )),
+    Err(Error::InvalidCharacter)
+  );
+  assert_eq!(
+    did_dht::decode(&did!(
+      "did:dht:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6b"
+    )),
+    Err(Error::NonCanonical)
+  );
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "did-ethr")]
+
+use did_url::did;
+use did_url::did_ethr;
+use did_url::did_ethr::Error;
+use did_url::did_ethr::EthrDid;
+use did_url::did_ethr::Identifier;
+use did_url::did_ethr::Network;
+use did_url::DID;
+
+const ADDRESS: &str = "0xb9c5714089478a327f09197987f16f9e5d936e8a";
+const CHECKSUM: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const PUBLIC_KEY: &str = "0x03fdd57adec3d438ea237fe46b33ee1e016eda6b585c3e27ea66686c2ea5358479";
+
+#[test]
+#[rustfmt::skip]
+fn test_decode() {
+  let cases: &[(&str, Network, Option<u64>)] = &[
+    ("did:ethr:0xb9c5714089478a327f09197987f16f9e5d936e8a", Network::Mainnet, Some(1)),
+    ("did:ethr:goerli:0xb9c5714089478a327f09197987f16f9e5d936e8a", Network::Name("goerli".into()), Some(5)),
+    ("did:ethr:rsk:testnet:0xb9c5714089478a327f09197987f16f9e5d936e8a", Network::Name("rsk:testnet".into()), Some(31)),
+    ("did:ethr:private:0xb9c5714089478a327f09197987f16f9e5d936e8a", Network::Name("private".into()), None),
+    ("did:ethr:0x5:0xb9c5714089478a327f09197987f16f9e5d936e8a", Network::ChainId(5), Some(5)),
+    ("did:ethr:0xaa36a7:0xb9c5714089478a327f09197987f16f9e5d936e8a", Network::ChainId(11155111), Some(11155111)),
+  ];
+
+  for (did, network, chain_id) in cases {
+    let did: DID = did!(*did);
+    let ethr: EthrDid = did_ethr::decode(&did).unwrap();
+
+    assert_eq!(ethr.network(), network, "{}", did);
+    assert_eq!(ethr.chain_id(), *chain_id, "{}", did);
+    assert_eq!(ethr.identifier().address(), Some(ADDRESS));
+    assert_eq!(did_ethr::encode(&ethr), did);
+  }
+}
+
+#[test]
+fn test_identifier() {
+  let did: DID = DID::parse(format!("did:ethr:{}", PUBLIC_KEY)).unwrap();
+  let ethr: EthrDid = did_ethr::decode(&did).unwrap();
+  let public_key: &[u8; 33] = ethr.identifier().public_key().unwrap();
+
+  assert_eq!(public_key[0], 0x03);
+  assert_eq!(public_key[32], 0x79);
+  assert_eq!(ethr.identifier().address(), None);
+  assert_eq!(did_ethr::encode(&ethr), did);
+
+  let did: DID = DID::parse(format!("did:ethr:{}", CHECKSUM)).unwrap();
+  assert!(did_ethr::decode(&did).is_ok());
+
+  let did: DID = DID::parse(format!(
+    "did:ethr:{}",
+    CHECKSUM.to_ascii_uppercase().replace("0X", "0x")
+  ))
+  .unwrap();
+  assert!(did_ethr::decode(&did).is_ok());
+}
+
+#[test]
+fn test_new() {
+  let identifier: Identifier = Identifier::parse(ADDRESS).unwrap();
+
+  let ethr: EthrDid = EthrDid::new(Network::ChainId(137), identifier.clone()).unwrap();
+  assert_eq!(
+    did_ethr::encode(&ethr),
+    format!("did:ethr:0x89:{}", ADDRESS).as_str()
+  );
+
+  let ethr: EthrDid = EthrDid::new(Network::Mainnet, identifier.clone()).unwrap();
+  assert_eq!(
+    did_ethr::encode(&ethr),
+    format!("did:ethr:{}", ADDRESS).as_str()
+  );
+
+  // `mainnet` is normalized to the default network.
+  let did: DID = DID::parse(format!("did:ethr:mainnet:{}", ADDRESS)).unwrap();
+  assert_eq!(did_ethr::decode(&did).unwrap(), ethr);
+
+  assert_eq!(
+    EthrDid::new(Network::Name("0x5".into()), identifier.clone()),
+    Err(Error::InvalidNetwork)
+  );
+  assert_eq!(
+    EthrDid::new(Network::Name("a b".into()), identifier),
+    Err(Error::InvalidNetwork)
+  );
+  assert_eq!(
+    EthrDid::new(Network::Mainnet, Identifier::Address("0x123".into())),
+    Err(Error::InvalidIdentifier)
+  );
+}
+
+#[test]
+fn test_invalid() {
+  let mut invalid: String = CHECKSUM.to_string();
+  invalid.replace_range(41.., "D");
+
+  assert_eq!(Identifier::parse(&invalid), Err(Error::InvalidChecksum));
+  assert_eq!(Identifier::parse("0x123"), Err(Error::InvalidIdentifier));
+  assert_eq!(
+    Identifier::parse(&ADDRESS[2..]),
+    Err(Error::InvalidIdentifier)
+  );
+  assert_eq!(
+    Identifier::parse(&PUBLIC_KEY.replace("0x03", "0x04")),
+    Err(Error::InvalidPublicKey)
+  );
+
+  assert_eq!(Network::parse("0x"), Err(Error::InvalidNetwork));
+  assert_eq!(Network::parse("0xg"), Err(Error::InvalidNetwork));
+  assert_eq!(
+    Network::parse("0x10000000000000000"),
+    Err(Error::InvalidNetwork)
+  );
+  assert_eq!(Network::parse("rsk::testnet"), Err(Error::InvalidNetwork));
+  assert_eq!(Network::parse("Goerli"), Err(Error::InvalidNetwork));
+
+  assert_eq!(
+    did_ethr::decode(&did!("did:pkh:eip155:1:0x0")),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_ethr::decode(&did!("did:ethr:goerli")),
+    Err(Error::InvalidIdentifier)
+  );
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "did-indy")]
+
+use did_url::did;
+use did_url::did_indy;
+use did_url::did_indy::Error;
+use did_url::did_indy::IndyDid;
+use did_url::did_indy::LedgerObject;
+use did_url::did_indy::Nym;
+use did_url::DID;
+
+#[test]
+#[rustfmt::skip]
+fn test_decode() {
+  let cases: &[(&str, &str, Option<&str>, &str)] = &[
+    ("did:indy:sovrin:WRfXPg8dantKVubE3HX8pw", "sovrin", None, "WRfXPg8dantKVubE3HX8pw"),
+    ("did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw", "sovrin", Some("staging"), "WRfXPg8dantKVubE3HX8pw"),
+    ("did:indy:idunion:H3C2AVvLMv6gmMNam3uVAjZpfkcJCwDwnZn6z3wXmqPV", "idunion", None, "H3C2AVvLMv6gmMNam3uVAjZpfkcJCwDwnZn6z3wXmqPV"),
+  ];
+
+  for (did, namespace, sub_namespace, nym) in cases {
+    let did: DID = did!(*did);
+    let indy: IndyDid = did_indy::decode(&did).unwrap();
+
+    assert_eq!(indy.namespace(), *namespace);
+    assert_eq!(indy.sub_namespace(), *sub_namespace);
+    assert_eq!(indy.nym().as_str(), *nym);
+    assert_eq!(did_indy::encode(&indy), did);
+  }
+
+  let indy: IndyDid = did_indy::decode(&did!("did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw")).unwrap();
+
+  assert_eq!(indy.ledger(), "sovrin:staging");
+  assert_eq!(indy.nym().to_bytes().len(), 16);
+}
+
+#[test]
+fn test_sov() {
+  let did: DID = did!("did:sov:WRfXPg8dantKVubE3HX8pw");
+  let nym: Nym = did_indy::decode_sov(&did).unwrap();
+
+  assert_eq!(nym.as_str(), "WRfXPg8dantKVubE3HX8pw");
+  assert_eq!(did_indy::encode_sov(&nym), did);
+
+  let did: DID = did!("did:sov:WRfXPg8dantKVubE3HX8pw#key-1");
+  let indy: DID = did_indy::sov_to_indy(&did, "sovrin:staging").unwrap();
+
+  assert_eq!(indy, "did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw#key-1");
+  assert_eq!(did_indy::indy_to_sov(&indy).unwrap(), did);
+
+  assert_eq!(
+    did_indy::sov_to_indy(&did, "sovrin").unwrap(),
+    "did:indy:sovrin:WRfXPg8dantKVubE3HX8pw#key-1"
+  );
+  assert_eq!(
+    did_indy::sov_to_indy(&did, "Sovrin"),
+    Err(Error::InvalidNamespace)
+  );
+  assert_eq!(
+    did_indy::sov_to_indy(&did, "sovrin:"),
+    Err(Error::InvalidNamespace)
+  );
+  assert_eq!(did_indy::indy_to_sov(&did), Err(Error::InvalidMethod));
+}
+
+#[test]
+fn test_ledger_object() {
+  let base: DID = did!("did:indy:sovrin:WRfXPg8dantKVubE3HX8pw");
+
+  assert_eq!(did_indy::ledger_object(&base), Ok(None));
+
+  let did: DID = base
+    .join("/anoncreds/v0/SCHEMA/degree%20schema/1.5.7")
+    .unwrap();
+
+  assert_eq!(
+    did_indy::ledger_object(&did),
+    Ok(Some(LedgerObject::Schema {
+      name: "degree schema".into(),
+      version: "1.5.7".into(),
+    }))
+  );
+
+  let did: DID = base.join("/anoncreds/v0/CLAIM_DEF/56495/npdb").unwrap();
+
+  assert_eq!(
+    did_indy::ledger_object(&did),
+    Ok(Some(LedgerObject::ClaimDef {
+      schema_seq_no: 56495,
+      tag: "npdb".into(),
+    }))
+  );
+
+  let did: DID = base
+    .join("/anoncreds/v0/REV_REG_DEF/56495/npdb/TAG1")
+    .unwrap();
+
+  assert_eq!(
+    did_indy::ledger_object(&did),
+    Ok(Some(LedgerObject::RevRegDef {
+      schema_seq_no: 56495,
+      claim_def_tag: "npdb".into(),
+      tag: "TAG1".into(),
+    }))
+  );
+
+  let did: DID = base
+    .join("/anoncreds/v0/REV_REG_ENTRY/56495/npdb/TAG1")
+    .unwrap();
+
+  assert_eq!(
+    did_indy::ledger_object(&did),
+    Ok(Some(LedgerObject::RevRegEntry {
+      schema_seq_no: 56495,
+      claim_def_tag: "npdb".into(),
+      tag: "TAG1".into(),
+    }))
+  );
+
+  for path in &[
+    "/other",
+    "/anoncreds/v0/SCHEMA/name",
+    "/anoncreds/v0/SCHEMA/name/",
+    "/anoncreds/v0/SCHEMA//1.0",
+    "/anoncreds/v0/CLAIM_DEF/abc/npdb",
+    "/anoncreds/v0/CLAIM_DEF/+1/npdb",
+    "/anoncreds/v0/UNKNOWN/1/2",
+    "/anoncreds/v0/SCHEMA/%FF/1.0",
+  ] {
+    let did: DID = base.join(path).unwrap();
+    assert_eq!(
+      did_indy::ledger_object(&did),
+      Err(Error::InvalidObject),
+      "{}",
+      path
+    );
+  }
+}
+
+#[test]
+fn test_new() {
+  let nym: Nym = Nym::new("WRfXPg8dantKVubE3HX8pw").unwrap();
+
+  let indy: IndyDid = IndyDid::new("sovrin", Some("staging"), nym.clone()).unwrap();
+  assert_eq!(
+    did_indy::encode(&indy),
+    "did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw"
+  );
+
+  let indy: IndyDid = IndyDid::new("sovrin", None, nym.clone()).unwrap();
+  assert_eq!(indy.sub_namespace(), None);
+
+  assert_eq!(
+    IndyDid::new("sovrin", Some(""), nym.clone()),
+    Err(Error::InvalidNamespace)
+  );
+  assert_eq!(
+    IndyDid::new("Sovrin", None, nym),
+    Err(Error::InvalidNamespace)
+  );
+}
+
+#[test]
+fn test_invalid() {
+  assert_eq!(Nym::new("WRfXPg8dantKVubE3HX8"), Err(Error::InvalidNym));
+  assert_eq!(Nym::new("0OIl"), Err(Error::InvalidNym));
+  assert_eq!(Nym::new(""), Err(Error::InvalidNym));
+
+  assert_eq!(
+    did_indy::decode(&did!("did:indy:WRfXPg8dantKVubE3HX8pw")),
+    Err(Error::InvalidNamespace)
+  );
+  assert_eq!(
+    did_indy::decode(&did!("did:indy:Sovrin:WRfXPg8dantKVubE3HX8pw")),
+    Err(Error::InvalidNamespace)
+  );
+  assert_eq!(
+    did_indy::decode(&did!("did:indy:a:b:c:WRfXPg8dantKVubE3HX8pw")),
+    Err(Error::InvalidNym)
+  );
+  assert_eq!(
+    did_indy::decode(&did!("did:sov:WRfXPg8dantKVubE3HX8pw")),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_indy::decode_sov(&did!("did:sov:sovrin:WRfXPg8dantKVubE3HX8pw")),
+    Err(Error::InvalidNym)
+  );
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "did-ion")]
+
+use did_url::did;
+use did_url::did_ion;
+use did_url::did_ion::Error;
+use did_url::did_ion::InitialState;
+use did_url::did_ion::Sidetree;
+use did_url::DID;
+use serde_json::json;
+use serde_json::Value;
+
+const SUFFIX: &str = "EiDqDRTHY4QAs3hw95H9_XNkjimWpHT6BJhaaVXYMJDCfQ";
+const LONG_FORM: &str = "eyJkZWx0YSI6eyJwYXRjaGVzIjpbeyJhY3Rpb24iOiJyZXBsYWNlIiwiZG9jdW1lbnQiOnsicHVibGljS2V5cyI6W10sInNlcnZpY2VzIjpbXX19XSwidXBkYXRlQ29tbWl0bWVudCI6IkVpREtJa3dxTzY5SVBHM3BPbEhrZGI4Nm5ZdDBhTnhTSFp1MnItYmhFem5qZEEifSwic3VmZml4RGF0YSI6eyJkZWx0YUhhc2giOiJFaUNmRFdSbllsY0Q5RUdBM2RfNVoxQUh1LWlZcU1iSjluZmlxZHo1UzhWRGJnIiwidXBkYXRlQ29tbWl0bWVudCI6IkVpREtJa3dxTzY5SVBHM3BPbEhrZGI4Nm5ZdDBhTnhTSFp1MnItYmhFem5qZEEifX0";
+const LEGACY: &str = "eyJkZWx0YUhhc2giOiJFaUNmRFdSbllsY0Q5RUdBM2RfNVoxQUh1LWlZcU1iSjluZmlxZHo1UzhWRGJnIiwidXBkYXRlQ29tbWl0bWVudCI6IkVpREtJa3dxTzY5SVBHM3BPbEhrZGI4Nm5ZdDBhTnhTSFp1MnItYmhFem5qZEEifQ.eyJwYXRjaGVzIjpbeyJhY3Rpb24iOiJyZXBsYWNlIiwiZG9jdW1lbnQiOnsicHVibGljS2V5cyI6W10sInNlcnZpY2VzIjpbXX19XSwidXBkYXRlQ29tbWl0bWVudCI6IkVpREtJa3dxTzY5SVBHM3BPbEhrZGI4Nm5ZdDBhTnhTSFp1MnItYmhFem5qZEEifQ";
+
+fn initial_state() -> InitialState {
+  let suffix_data: Value = json!({
+    "updateCommitment": "EiDKIkwqO69IPG3pOlHkdb86nYt0aNxSHZu2r-bhEznjdA",
+    "deltaHash": "EiCfDWRnYlcD9EGA3d_5Z1AHu-iYqMbJ9nfiqdz5S8VDbg",
+  });
+
+  let delta: Value = json!({
+    "patches": [{ "action": "replace", "document": { "publicKeys": [], "services": [] } }],
+    "updateCommitment": "EiDKIkwqO69IPG3pOlHkdb86nYt0aNxSHZu2r-bhEznjdA",
+  });
+
+  match (suffix_data, delta) {
+    (Value::Object(suffix_data), Value::Object(delta)) => InitialState::new(suffix_data, delta),
+    _ => unreachable!(),
+  }
+}
+
+#[test]
+fn test_short_form() {
+  let did: DID = DID::parse(format!("did:ion:{}", SUFFIX)).unwrap();
+  let sidetree: Sidetree = did_ion::decode(&did).unwrap();
+
+  assert_eq!(sidetree.short_form(), &did);
+  assert_eq!(sidetree.suffix(), SUFFIX);
+  assert_eq!(sidetree.initial_state(), None);
+  assert_eq!(sidetree.long_form(), None);
+}
+
+#[test]
+fn test_long_form() {
+  let short: DID = DID::parse(format!("did:ion:{}", SUFFIX)).unwrap();
+  let long: DID = DID::parse(format!("did:ion:{}:{}", SUFFIX, LONG_FORM)).unwrap();
+
+  assert_eq!(initial_state().suffix(), SUFFIX);
+  assert_eq!(initial_state().to_long_form(), LONG_FORM);
+  assert_eq!(did_ion::encode(&initial_state()), long);
+
+  let sidetree: Sidetree = did_ion::decode(&long.join("#key-1").unwrap()).unwrap();
+
+  assert_eq!(sidetree.short_form(), &short);
+  assert_eq!(sidetree.suffix(), SUFFIX);
+  assert_eq!(sidetree.initial_state(), Some(&initial_state()));
+  assert_eq!(sidetree.long_form(), Some(long.clone()));
+
+  assert_eq!(did_ion::short_form(&long).unwrap(), short);
+  assert_eq!(did_ion::long_form(&short, &initial_state()).unwrap(), long);
+  assert_eq!(did_ion::long_form(&long, &initial_state()).unwrap(), long);
+}
+
+#[test]
+fn test_network() {
+  let short: DID = DID::parse(format!("did:ion:test:{}", SUFFIX)).unwrap();
+  let long: DID = DID::parse(format!("did:ion:test:{}:{}", SUFFIX, LONG_FORM)).unwrap();
+
+  assert_eq!(did_ion::short_form(&long).unwrap(), short);
+  assert_eq!(did_ion::short_form(&short).unwrap(), short);
+  assert_eq!(did_ion::decode(&short).unwrap().suffix(), SUFFIX);
+}
+
+#[test]
+fn test_legacy() {
+  let short: DID = DID::parse(format!("did:ion:{}", SUFFIX)).unwrap();
+
+  for key in &["initial-state", "-ion-initial-state"] {
+    let did: DID = DID::parse(format!("did:ion:{}?{}={}", SUFFIX, key, LEGACY)).unwrap();
+    let sidetree: Sidetree = did_ion::decode(&did).unwrap();
+
+    assert_eq!(sidetree.short_form(), &short);
+    assert_eq!(sidetree.initial_state(), Some(&initial_state()));
+  }
+}
+
+#[test]
+fn test_invalid() {
+  let other: &str = "EiA0Bcxp7jC-qLBtjEfJ4_wU3hbeH1TMLk4Uu-BEIEq5ZQ";
+
+  assert_eq!(
+    did_ion::decode(&did!("did:ion:abc")),
+    Err(Error::InvalidSuffix)
+  );
+  assert_eq!(
+    did_ion::decode(&did!(
+      "did:key:EiDyOQbbZAa3aiRzeCkV7LOx3SERjjH93EXoIM3UoN4oWg"
+    )),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_ion::decode(&did!("did:ion:abc:eyJ9")),
+    Err(Error::InvalidSuffix)
+  );
+
+  let did: DID = DID::parse(format!("did:ion:{}:{}", other, LONG_FORM)).unwrap();
+  assert_eq!(did_ion::decode(&did), Err(Error::HashMismatch));
+
+  let did: DID = DID::parse(format!("did:ion:{}?initial-state={}", other, LEGACY)).unwrap();
+  assert_eq!(did_ion::decode(&did), Err(Error::HashMismatch));
+
+  let did: DID = DID::parse(format!(
+    "did:ion:{}:{}?initial-state={}",
+    SUFFIX, LONG_FORM, LEGACY
+  ))
+  .unwrap();
+  assert_eq!(did_ion::decode(&did), Err(Error::InvalidInitialState));
+
+  let did: DID = DID::parse(format!("did:ion:{}?initial-state=e30", SUFFIX)).unwrap();
+  assert_eq!(did_ion::decode(&did), Err(Error::InvalidInitialState));
+
+  let did: DID = DID::parse(format!("did:ion:{}:e30", SUFFIX)).unwrap();
+  assert_eq!(did_ion::decode(&did), Err(Error::InvalidInitialState));
+
+  let did: DID = DID::parse(format!("did:ion:{}:e", SUFFIX)).unwrap();
+  assert_eq!(did_ion::decode(&did), Err(Error::InvalidEncoding));
+
+  let did: DID = DID::parse(format!("did:ion:{}:bm90IGpzb24", SUFFIX)).unwrap();
+  assert_eq!(did_ion::decode(&did), Err(Error::InvalidJson));
+
+  let short: DID = DID::parse(format!("did:ion:{}", other)).unwrap();
+  assert_eq!(
+    did_ion::long_form(&short, &initial_state()),
+    Err(Error::HashMismatch)
+  );
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "did-jwk")]
+
+use did_url::did;
+use did_url::did_jwk;
+use did_url::did_jwk::Error;
+use did_url::did_jwk::Jwk;
+use did_url::DID;
+use serde_json::json;
+
+const P256: &str = "did:jwk:eyJjcnYiOiJQLTI1NiIsImt0eSI6IkVDIiwieCI6ImFjYklRaXVNczNpOF91c3pFakoydHBUdFJNNEVVM3l6OTFQSDZDZEgyVjAiLCJ5IjoiX0tjeUxqOXZXTXB0bm1LdG00NkdxRHo4d2Y3NEk1TEtncmwyR3pIM25TRSJ9";
+
+fn jwk(value: serde_json::Value) -> Jwk {
+  value.as_object().unwrap().clone()
+}
+
+#[test]
+fn test_decode() {
+  let jwk: Jwk = did_jwk::decode(&did!(P256)).unwrap();
+
+  assert_eq!(jwk["kty"], "EC");
+  assert_eq!(jwk["crv"], "P-256");
+  assert_eq!(jwk["x"], "acbIQiuMs3i8_uszEjJ2tpTtRM4EU3yz91PH6CdH2V0");
+  assert_eq!(jwk["y"], "_KcyLj9vWMptnmKtm46GqDz8wf74I5LKgrl2GzH3nSE");
+}
+
+#[test]
+fn test_encode() {
+  let jwk: Jwk = jwk(json!({
+    "y": "_KcyLj9vWMptnmKtm46GqDz8wf74I5LKgrl2GzH3nSE",
+    "x": "acbIQiuMs3i8_uszEjJ2tpTtRM4EU3yz91PH6CdH2V0",
+    "kty": "EC",
+    "crv": "P-256",
+  }));
+
+  let did: DID = did_jwk::encode(&jwk).unwrap();
+
+  assert_eq!(did, P256);
+  assert_eq!(did_jwk::decode(&did).unwrap(), jwk);
+}
+
+#[test]
+fn test_encode_canonical() {
+  use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+  use base64::Engine as _;
+
+  // Examples from RFC 8785 section 3.2.3 and appendix B.
+  let jwk: Jwk = jwk(json!({
+    "kty": "OKP",
+    "crv": "Ed25519",
+    "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+    "\u{20ac}": "Euro Sign",
+    "\r": "Carriage Return",
+    "\u{fb33}": "Hebrew Letter Dalet With Dagesh",
+    "1": "One",
+    "\u{1f600}": "Emoji: Grinning Face",
+    "\u{80}": "Control",
+    "\u{f6}": "Latin Small Letter O With Diaeresis",
+    "numbers": [
+      serde_json::from_str::<serde_json::Value>("333333333.33333329").unwrap(),
+      1E30,
+      4.50,
+      2e-3,
+      0.000000000000000000000000001,
+      1e21,
+      1e20,
+      -0.0,
+      9007199254740993u64,
+    ],
+  }));
+
+  let did: DID = did_jwk::encode(&jwk).unwrap();
+  let json: Vec<u8> = URL_SAFE_NO_PAD.decode(did.method_id()).unwrap();
+
+  assert_eq!(
+    String::from_utf8(json).unwrap(),
+    concat!(
+      "{\"\\r\":\"Carriage Return\",",
+      "\"1\":\"One\",",
+      "\"crv\":\"Ed25519\",",
+      "\"kty\":\"OKP\",",
+      "\"numbers\":[333333333.3333333,1e+30,4.5,0.002,1e-27,1e+21,100000000000000000000,0,9007199254740992],",
+      "\"x\":\"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo\",",
+      "\"\u{80}\":\"Control\",",
+      "\"\u{f6}\":\"Latin Small Letter O With Diaeresis\",",
+      "\"\u{20ac}\":\"Euro Sign\",",
+      "\"\u{1f600}\":\"Emoji: Grinning Face\",",
+      "\"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}",
+    )
+  );
+}
+
+#[test]
+fn test_verification_method() {
+  let did: DID = did!(P256);
+  let method: DID = did_jwk::verification_method(&did).unwrap();
+
+  assert_eq!(method.method_id(), did.method_id());
+  assert_eq!(method.fragment(), Some("0"));
+}
+
+#[test]
+fn test_invalid() {
+  let private: Jwk = jwk(json!({ "kty": "OKP", "crv": "Ed25519", "x": "AA", "d": "AA" }));
+  let symmetric: Jwk = jwk(json!({ "kty": "oct", "k": "AA" }));
+  let missing: Jwk = jwk(json!({ "crv": "Ed25519", "x": "AA" }));
+
+  assert_eq!(did_jwk::encode(&private), Err(Error::PrivateKey));
+  assert_eq!(did_jwk::encode(&symmetric), Err(Error::PrivateKey));
+  assert_eq!(did_jwk::encode(&missing), Err(Error::InvalidJwk));
+
+  // {"kty":"OKP","crv":"Ed25519","x":"AA","d":"AA"}
+  let did: DID = did!("did:jwk:eyJrdHkiOiJPS1AiLCJjcnYiOiJFZDI1NTE5IiwieCI6IkFBIiwiZCI6IkFBIn0");
+  assert_eq!(did_jwk::decode(&did), Err(Error::PrivateKey));
+
+  assert_eq!(
+    did_jwk::decode(&did!("did:key:z6Mk")),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_jwk::decode(&did!("did:jwk:a")),
+    Err(Error::InvalidEncoding)
+  );
+  assert_eq!(
+    did_jwk::decode(&did!("did:jwk:bm90LWpzb24")),
+    Err(Error::InvalidJson)
+  );
+  assert_eq!(
+    did_jwk::decode(&did!("did:jwk:WzFd")),
+    Err(Error::InvalidJwk)
+  );
+}
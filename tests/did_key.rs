@@ -0,0 +1,92 @@
+#![cfg(feature = "did-key")]
+
+use did_url::did;
+use did_url::did_key;
+use did_url::did_key::Codec;
+use did_url::did_key::Error;
+use did_url::did_key::PublicKey;
+use did_url::DID;
+
+#[test]
+#[rustfmt::skip]
+fn test_decode() {
+  let cases: &[(&str, Codec, usize)] = &[
+    ("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK", Codec::Ed25519, 32),
+    ("did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F", Codec::X25519, 32),
+    ("did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme", Codec::Secp256k1, 33),
+    ("did:key:zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169", Codec::P256, 33),
+    ("did:key:z82Lm1MpAkeJcix9K8TMiLd5NMAhnwkjjCBeWHXyu3U4oT2MVJJKXkcVBgjGhnLBn2Kaau9", Codec::P384, 49),
+  ];
+
+  for (did, codec, length) in cases {
+    let did: DID = did!(*did);
+    let key: PublicKey = did_key::decode(&did).unwrap();
+
+    assert_eq!(key.codec(), *codec, "{}", did);
+    assert_eq!(key.as_bytes().len(), *length, "{}", did);
+    assert_eq!(did_key::encode(&key), did);
+  }
+}
+
+#[test]
+fn test_encode() {
+  let key: PublicKey = PublicKey::new(Codec::Ed25519, [0; 32]).unwrap();
+  let did: DID = did_key::encode(&key);
+
+  assert!(did.method_id().starts_with("z6Mk"));
+  assert_eq!(did_key::decode(&did).unwrap(), key);
+
+  let key: PublicKey = PublicKey::new(Codec::Bls12381G2, vec![1; 96]).unwrap();
+  let did: DID = did_key::encode(&key);
+
+  assert_eq!(did_key::decode(&did).unwrap(), key);
+
+  let key: PublicKey = PublicKey::new(Codec::Rsa, vec![2; 270]).unwrap();
+  assert_eq!(did_key::decode(&did_key::encode(&key)).unwrap(), key);
+}
+
+#[test]
+fn test_verification_method() {
+  let did: DID = did!("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK");
+
+  assert_eq!(
+    did_key::verification_method(&did).unwrap(),
+    "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK#z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+  );
+}
+
+#[test]
+fn test_invalid() {
+  assert_eq!(
+    PublicKey::new(Codec::Ed25519, [0; 31]),
+    Err(Error::InvalidKeyLength)
+  );
+  assert_eq!(PublicKey::new(Codec::Rsa, []), Err(Error::InvalidKeyLength));
+
+  assert_eq!(
+    did_key::decode(&did!("did:web:example.com")),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_key::decode(&did!("did:key:hello")),
+    Err(Error::InvalidMultibase)
+  );
+  assert_eq!(
+    did_key::decode(&did!("did:key:z0OIl")),
+    Err(Error::InvalidMultibase)
+  );
+  assert_eq!(
+    did_key::decode(&did!("did:key:z")),
+    Err(Error::InvalidMulticodec)
+  );
+  assert_eq!(
+    did_key::decode(&did!("did:key:z2")),
+    Err(Error::UnsupportedCodec)
+  );
+
+  // ed25519-pub with a 16-byte key
+  assert_eq!(
+    did_key::decode(&did!("did:key:zAq9rGLRDsNsi1PCZE8r3xjyUK")),
+    Err(Error::InvalidKeyLength)
+  );
+}
@@ -0,0 +1,243 @@
+#![cfg(feature = "did-peer")]
+
+use did_url::did;
+use did_url::did_key::Codec;
+use did_url::did_peer;
+use did_url::did_peer::Document;
+use did_url::did_peer::Element;
+use did_url::did_peer::Error;
+use did_url::did_peer::Numalgo;
+use did_url::did_peer::Peer;
+use did_url::did_peer::Purpose;
+use did_url::DID;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+const ED25519: &str = "z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+const X25519: &str = "z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F";
+const SERVICE: &str = "eyJ0IjoiZG0iLCJzIjp7InVyaSI6Imh0dHBzOi8vZXhhbXBsZS5jb20vZGlkY29tbSIsImEiOlsiZGlkY29tbS92MiJdLCJyIjpbImRpZDpleGFtcGxlOjEyMyNrZXktMSJdfX0";
+const SERVICE_ID: &str =
+  "eyJ0IjoiZXhhbXBsZSIsInMiOiJodHRwczovL2V4YW1wbGUuY29tL290aGVyIiwiaWQiOiIjb3RoZXIifQ";
+
+fn numalgo2(elements: &[(char, &str)]) -> DID {
+  let mut output: String = String::from("did:peer:2");
+
+  for (prefix, value) in elements {
+    output.push('.');
+    output.push(*prefix);
+    output.push_str(value);
+  }
+
+  DID::parse(output).unwrap()
+}
+
+#[test]
+fn test_numalgo() {
+  assert_eq!(
+    did_peer::numalgo(&did!("did:peer:0z6Mk")),
+    Ok(Numalgo::Zero)
+  );
+  assert_eq!(
+    did_peer::numalgo(&did!("did:peer:2.Vz6Mk")),
+    Ok(Numalgo::Two)
+  );
+  assert_eq!(did_peer::numalgo(&did!("did:peer:4zQm")), Ok(Numalgo::Four));
+  assert_eq!(
+    did_peer::numalgo(&did!("did:peer:5abc")),
+    Err(Error::InvalidNumalgo)
+  );
+  assert_eq!(
+    did_peer::numalgo(&did!("did:key:z6Mk")),
+    Err(Error::InvalidMethod)
+  );
+}
+
+#[test]
+fn test_numalgo0() {
+  let did: DID = DID::parse(format!("did:peer:0{}", ED25519)).unwrap();
+
+  match did_peer::decode(&did).unwrap() {
+    Peer::Key(key) => assert_eq!(key.codec(), Codec::Ed25519),
+    peer => panic!("unexpected {:?}", peer),
+  }
+
+  let document: Document = did_peer::expand(&did).unwrap();
+
+  assert_eq!(document.verification_methods().len(), 1);
+  assert_eq!(
+    document.verification_methods()[0].id().fragment(),
+    Some(ED25519)
+  );
+  assert_eq!(
+    document.verification_methods()[0].purpose(),
+    Purpose::Verification
+  );
+
+  let did: DID = DID::parse(format!("did:peer:0{}", X25519)).unwrap();
+  let document: Document = did_peer::expand(&did).unwrap();
+
+  assert_eq!(
+    document.verification_methods()[0].purpose(),
+    Purpose::Encryption
+  );
+}
+
+#[test]
+fn test_numalgo2() {
+  let did: DID = numalgo2(&[('E', X25519), ('V', ED25519), ('S', SERVICE)]);
+
+  match did_peer::decode(&did).unwrap() {
+    Peer::Elements(elements) => {
+      assert_eq!(elements.len(), 3);
+      assert!(matches!(&elements[0], Element::Key(Purpose::Encryption, _)));
+      assert!(matches!(
+        &elements[1],
+        Element::Key(Purpose::Verification, _)
+      ));
+      assert!(matches!(&elements[2], Element::Service(_)));
+    }
+    peer => panic!("unexpected {:?}", peer),
+  }
+
+  let document: Document = did_peer::expand(&did).unwrap();
+
+  assert_eq!(
+    document.to_json(),
+    json!({
+      "id": did.as_str(),
+      "verificationMethod": [
+        {
+          "id": "#key-1",
+          "type": "Multikey",
+          "controller": did.as_str(),
+          "publicKeyMultibase": X25519,
+        },
+        {
+          "id": "#key-2",
+          "type": "Multikey",
+          "controller": did.as_str(),
+          "publicKeyMultibase": ED25519,
+        },
+      ],
+      "authentication": ["#key-2"],
+      "keyAgreement": ["#key-1"],
+      "service": [
+        {
+          "id": "#service",
+          "type": "DIDCommMessaging",
+          "serviceEndpoint": {
+            "uri": "https://example.com/didcomm",
+            "accept": ["didcomm/v2"],
+            "routingKeys": ["did:example:123#key-1"],
+          },
+        },
+      ],
+    })
+  );
+}
+
+#[test]
+fn test_numalgo2_services() {
+  let did: DID = numalgo2(&[
+    ('V', ED25519),
+    ('S', SERVICE),
+    ('S', SERVICE_ID),
+    ('S', SERVICE),
+  ]);
+
+  let document: Document = did_peer::expand(&did.join("#key-1").unwrap()).unwrap();
+  let ids: Vec<Option<&str>> = document
+    .services()
+    .iter()
+    .map(|service| service.id().fragment())
+    .collect();
+
+  assert_eq!(document.id(), &did);
+  assert_eq!(ids, [Some("service"), Some("other"), Some("service-2")]);
+  assert_eq!(document.services()[1].properties()["type"], "example");
+}
+
+#[test]
+fn test_numalgo4() {
+  let mut input: Map<String, Value> = Map::new();
+
+  input.insert("@context".into(), json!(["https://www.w3.org/ns/did/v1"]));
+  input.insert("service".into(), json!([]));
+
+  let long: DID = did_peer::encode_long_form(&input);
+  let short: DID = did_peer::short_form(&long).unwrap();
+
+  assert!(long.method_id().starts_with(short.method_id()));
+  assert_eq!(long.method_id().as_bytes()[short.method_id().len()], b':');
+  assert_eq!(did_peer::numalgo(&short), Ok(Numalgo::Four));
+  assert_eq!(did_peer::short_form(&short).unwrap(), short);
+
+  match did_peer::decode(&long).unwrap() {
+    Peer::Hash { hash, document } => {
+      assert_eq!(hash, &short.method_id()[1..]);
+      assert_eq!(document, Some(input));
+    }
+    peer => panic!("unexpected {:?}", peer),
+  }
+
+  match did_peer::decode(&short).unwrap() {
+    Peer::Hash { document, .. } => assert_eq!(document, None),
+    peer => panic!("unexpected {:?}", peer),
+  }
+
+  let other: DID = did_peer::encode_long_form(&Map::new());
+  let encoded: &str = other.method_id().split(':').nth(1).unwrap();
+  let tampered: DID = DID::parse(format!("{}:{}", short, encoded)).unwrap();
+
+  assert_eq!(did_peer::decode(&tampered), Err(Error::HashMismatch));
+  assert_eq!(did_peer::expand(&long), Err(Error::UnsupportedNumalgo));
+}
+
+#[test]
+fn test_invalid() {
+  assert_eq!(
+    did_peer::decode(&did!("did:peer:1zQm")),
+    Err(Error::UnsupportedNumalgo)
+  );
+  assert_eq!(
+    did_peer::decode(&did!("did:peer:0z")),
+    Err(Error::InvalidKey)
+  );
+  assert_eq!(
+    did_peer::decode(&did!("did:peer:2")),
+    Err(Error::InvalidElement)
+  );
+  assert_eq!(
+    did_peer::decode(&did!("did:peer:2Vz6Mk")),
+    Err(Error::InvalidElement)
+  );
+  assert_eq!(
+    did_peer::decode(&did!("did:peer:2..")),
+    Err(Error::InvalidElement)
+  );
+  assert_eq!(
+    did_peer::decode(&numalgo2(&[('X', ED25519)])),
+    Err(Error::InvalidElement)
+  );
+  assert_eq!(
+    did_peer::decode(&numalgo2(&[('V', "z")])),
+    Err(Error::InvalidKey)
+  );
+  assert_eq!(
+    did_peer::decode(&numalgo2(&[('S', "WzFd")])),
+    Err(Error::InvalidService)
+  );
+  assert_eq!(
+    did_peer::decode(&numalgo2(&[('S', "e30-")])),
+    Err(Error::InvalidService)
+  );
+  assert_eq!(
+    did_peer::decode(&did!("did:peer:4zQm")),
+    Err(Error::InvalidHash)
+  );
+  assert_eq!(
+    did_peer::decode(&did!("did:peer:4z6Mk")),
+    Err(Error::InvalidHash)
+  );
+}
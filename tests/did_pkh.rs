@@ -0,0 +1,97 @@
+#![cfg(feature = "did-pkh")]
+
+use did_url::did;
+use did_url::did_pkh;
+use did_url::did_pkh::AccountId;
+use did_url::did_pkh::ChainId;
+use did_url::did_pkh::Error;
+use did_url::DID;
+
+#[test]
+#[rustfmt::skip]
+fn test_decode() {
+  let cases: &[(&str, &str, &str, &str)] = &[
+    ("did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a", "eip155", "1", "0xb9c5714089478a327f09197987f16f9e5d936e8a"),
+    ("did:pkh:eip155:1:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "eip155", "1", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+    ("did:pkh:bip122:000000000019d6689c085ae165831e93:128Lkh3S7CkDTBZ8W7BbpsN3YYizJMp8p6", "bip122", "000000000019d6689c085ae165831e93", "128Lkh3S7CkDTBZ8W7BbpsN3YYizJMp8p6"),
+    ("did:pkh:solana:4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZ:CKg5d12Jhpej1JqtmxLJgaFqqeYjxgPqToJ4LBdvG9Ev", "solana", "4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZ", "CKg5d12Jhpej1JqtmxLJgaFqqeYjxgPqToJ4LBdvG9Ev"),
+  ];
+
+  for (did, namespace, reference, address) in cases {
+    let did: DID = did!(*did);
+    let account: AccountId = did_pkh::decode(&did).unwrap();
+
+    assert_eq!(account.chain_id().namespace(), *namespace);
+    assert_eq!(account.chain_id().reference(), *reference);
+    assert_eq!(account.address(), *address);
+    assert_eq!(account.to_string(), did.method_id());
+    assert_eq!(did_pkh::encode(&account), did);
+  }
+}
+
+#[test]
+fn test_encode() {
+  let chain: ChainId = ChainId::new("eip155", "137").unwrap();
+  let account: AccountId =
+    AccountId::new(chain, "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359").unwrap();
+
+  assert_eq!(
+    did_pkh::encode(&account),
+    "did:pkh:eip155:137:0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+  );
+}
+
+#[test]
+fn test_invalid() {
+  assert_eq!(ChainId::new("ei", "1"), Err(Error::InvalidNamespace));
+  assert_eq!(ChainId::new("eip155155", "1"), Err(Error::InvalidNamespace));
+  assert_eq!(ChainId::new("EIP155", "1"), Err(Error::InvalidNamespace));
+  assert_eq!(ChainId::new("eip155", ""), Err(Error::InvalidReference));
+  assert_eq!(
+    ChainId::new("eip155", "a".repeat(33)),
+    Err(Error::InvalidReference)
+  );
+
+  let chain: ChainId = ChainId::new("eip155", "1").unwrap();
+
+  assert_eq!(
+    AccountId::new(chain.clone(), "0x123"),
+    Err(Error::InvalidAddress)
+  );
+  assert_eq!(
+    AccountId::new(chain.clone(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+    Err(Error::InvalidChecksum)
+  );
+
+  let chain: ChainId = ChainId::new("cosmos", "cosmoshub-3").unwrap();
+
+  assert_eq!(
+    AccountId::new(chain.clone(), ""),
+    Err(Error::InvalidAddress)
+  );
+  assert_eq!(
+    AccountId::new(chain.clone(), "a_b"),
+    Err(Error::InvalidAddress)
+  );
+  assert_eq!(
+    AccountId::new(chain.clone(), "a%2"),
+    Err(Error::InvalidAddress)
+  );
+  assert_eq!(
+    AccountId::new(chain, "a".repeat(129)),
+    Err(Error::InvalidAddress)
+  );
+
+  assert_eq!(
+    did_pkh::decode(&did!("did:key:z6Mk")),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_pkh::decode(&did!("did:pkh:eip155:1")),
+    Err(Error::InvalidAddress)
+  );
+  assert_eq!(
+    did_pkh::decode(&did!("did:pkh:eip155")),
+    Err(Error::InvalidReference)
+  );
+}
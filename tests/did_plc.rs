@@ -0,0 +1,91 @@
+#![cfg(feature = "did-plc")]
+
+use did_url::did;
+use did_url::did_plc;
+use did_url::did_plc::Error;
+use did_url::did_plc::Options;
+use did_url::did_plc::PlcId;
+use did_url::DID;
+
+#[test]
+fn test_decode() {
+  let did: DID = did!("did:plc:ewvi7nxzyoun6zhxrhs64oiz");
+  let id: PlcId = did_plc::decode(&did, Options::new()).unwrap();
+
+  assert_eq!(id.as_str(), "ewvi7nxzyoun6zhxrhs64oiz");
+  assert_eq!(did_plc::encode(&id), did);
+  assert_eq!(
+    did_plc::decode(&did, Options::new().strict(true)).unwrap(),
+    id
+  );
+}
+
+#[test]
+fn test_from_hash() {
+  // sha256("genesis")
+  let hash: [u8; 32] = [
+    0xae, 0xeb, 0xad, 0x4a, 0x79, 0x6f, 0xcc, 0x2e, 0x15, 0xdc, 0x4c, 0x60, 0x61, 0xb4, 0x5e, 0xd9,
+    0xb3, 0x73, 0xf2, 0x6a, 0xdf, 0xc7, 0x98, 0xca, 0x7d, 0x2d, 0x8c, 0xc5, 0x81, 0x82, 0x71, 0x8e,
+  ];
+
+  let id: PlcId = PlcId::from_hash(&hash).unwrap();
+
+  assert_eq!(id.as_str(), "v3v22stzn7gc4fo4jrqgdnc6");
+  assert_eq!(PlcId::from_hash(&hash[..15]).unwrap(), id);
+  assert_eq!(PlcId::from_hash(&hash[..14]), Err(Error::InvalidHashLength));
+}
+
+#[test]
+fn test_case() {
+  let upper: DID = did!("did:plc:EWVI7NXZYOUN6ZHXRHS64OIZ");
+  let mixed: DID = did!("did:plc:ewvi7nxzyoun6zhxrhs64oiZ");
+
+  assert_eq!(
+    did_plc::decode(&upper, Options::new()).unwrap().as_str(),
+    "ewvi7nxzyoun6zhxrhs64oiz"
+  );
+  assert_eq!(
+    did_plc::decode(&mixed, Options::new()).unwrap().as_str(),
+    "ewvi7nxzyoun6zhxrhs64oiz"
+  );
+
+  let options: Options = Options::new().strict(true);
+
+  assert_eq!(did_plc::decode(&upper, options), Err(Error::InvalidCase));
+  assert_eq!(did_plc::decode(&mixed, options), Err(Error::InvalidCase));
+}
+
+#[test]
+fn test_invalid() {
+  let options: Options = Options::new();
+
+  assert_eq!(
+    PlcId::new("ewvi7nxzyoun6zhxrhs64oi", options),
+    Err(Error::InvalidLength)
+  );
+  assert_eq!(
+    PlcId::new("ewvi7nxzyoun6zhxrhs64oizz", options),
+    Err(Error::InvalidLength)
+  );
+  assert_eq!(
+    PlcId::new("ewvi7nxzyoun6zhxrhs64oi1", options),
+    Err(Error::InvalidCharacter)
+  );
+  assert_eq!(
+    PlcId::new("ewvi7nxzyoun6zhxrhs64oi8", options),
+    Err(Error::InvalidCharacter)
+  );
+  assert_eq!(
+    PlcId::new("ewvi7nxzyoun6zhxrhs64oi=", options),
+    Err(Error::InvalidCharacter)
+  );
+  assert_eq!(
+    PlcId::new("ewvi7nxzyoun6zhxrhs64\u{e9}", options),
+    Err(Error::InvalidLength)
+  );
+
+  assert_eq!(
+    did_plc::decode(&did!("did:web:ewvi7nxzyoun6zhxrhs64oiz"), options),
+    Err(Error::InvalidMethod)
+  );
+}
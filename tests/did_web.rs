@@ -0,0 +1,172 @@
+#![cfg(feature = "did-web")]
+
+use did_url::did;
+use did_url::did_web;
+use did_url::did_web::Error;
+use did_url::did_web::Options;
+use did_url::DID;
+
+#[test]
+#[rustfmt::skip]
+fn test_to_url() {
+  let cases: &[(&str, &str)] = &[
+    ("did:web:w3c-ccg.github.io",                 "https://w3c-ccg.github.io/.well-known/did.json"),
+    ("did:web:w3c-ccg.github.io:user:alice",      "https://w3c-ccg.github.io/user/alice/did.json"),
+    ("did:web:example.com%3A3000",                "https://example.com:3000/.well-known/did.json"),
+    ("did:web:example.com%3a3000:user:alice",     "https://example.com:3000/user/alice/did.json"),
+    ("did:web:Example.COM:a%20b",                 "https://example.com/a%20b/did.json"),
+    ("did:web:xn--mnchen-3ya.de",                 "https://xn--mnchen-3ya.de/.well-known/did.json"),
+    ("did:web:example.com#key-1",                 "https://example.com/.well-known/did.json"),
+  ];
+
+  for (did, url) in cases {
+    assert_eq!(did_web::to_url(&did!(*did), Options::new()).unwrap(), *url, "{}", did);
+  }
+}
+
+#[test]
+fn test_to_url_invalid() {
+  let options: Options = Options::new();
+
+  assert_eq!(
+    did_web::to_url(&did!("did:key:z6Mk"), options),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:example.com%3A"), options),
+    Err(Error::InvalidPort)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:example.com%3A99999"), options),
+    Err(Error::InvalidPort)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:example.com::a"), options),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:example.com:..:bob"), options),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:example.com:a:."), options),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:example.com:%2e%2E"), options),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:exa_mple.com"), options),
+    Err(Error::InvalidDomain)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:%3A80"), options),
+    Err(Error::InvalidDomain)
+  );
+}
+
+#[test]
+fn test_strict() {
+  let strict: Options = Options::new().strict(true);
+
+  assert!(did_web::to_url(&did!("did:web:localhost%3A8080"), Options::new()).is_ok());
+  assert!(did_web::to_url(&did!("did:web:127.0.0.1"), Options::new()).is_ok());
+
+  assert_eq!(
+    did_web::to_url(&did!("did:web:localhost%3A8080"), strict),
+    Err(Error::Localhost)
+  );
+  assert_eq!(
+    did_web::to_url(&did!("did:web:127.0.0.1"), strict),
+    Err(Error::IpLiteral)
+  );
+  assert_eq!(
+    did_web::from_url("https://[::1]/did.json", Options::new()),
+    Err(Error::IpLiteral)
+  );
+  assert_eq!(
+    did_web::from_url("https://a.localhost/did.json", strict),
+    Err(Error::Localhost)
+  );
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_from_url() {
+  let cases: &[(&str, &str)] = &[
+    ("https://w3c-ccg.github.io/.well-known/did.json", "did:web:w3c-ccg.github.io"),
+    ("https://w3c-ccg.github.io",                      "did:web:w3c-ccg.github.io"),
+    ("https://w3c-ccg.github.io/user/alice/did.json",  "did:web:w3c-ccg.github.io:user:alice"),
+    ("HTTPS://example.com:3000/user/alice/",           "did:web:example.com%3A3000:user:alice"),
+    ("https://example.com/a%20b/did.json",             "did:web:example.com:a%20b"),
+    ("https://example.com/a:b/did.json",               "did:web:example.com:a%3Ab"),
+    ("https://münchen.de/.well-known/did.json",        "did:web:xn--mnchen-3ya.de"),
+  ];
+
+  for (url, did) in cases {
+    assert_eq!(did_web::from_url(url, Options::new()).unwrap(), *did, "{}", url);
+  }
+}
+
+#[test]
+fn test_round_trip_url() {
+  for url in &[
+    "https://example.com/.well-known/did.json",
+    "https://example.com/user/alice/did.json",
+    "https://example.com:3000/a%20b/did.json",
+  ] {
+    let did: DID = did_web::from_url(url, Options::new()).unwrap();
+    assert_eq!(did_web::to_url(&did, Options::new()).unwrap(), *url);
+  }
+}
+
+#[test]
+fn test_from_url_invalid() {
+  let options: Options = Options::new();
+
+  assert_eq!(
+    did_web::from_url("http://example.com", options),
+    Err(Error::InvalidUrl)
+  );
+  assert_eq!(
+    did_web::from_url("https://example.com/did.json?a", options),
+    Err(Error::InvalidUrl)
+  );
+  assert_eq!(
+    did_web::from_url("https://user@example.com", options),
+    Err(Error::InvalidUrl)
+  );
+  assert_eq!(
+    did_web::from_url("https://example.com//did.json", options),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(
+    did_web::from_url("https://example.com/did.json", options),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(
+    did_web::from_url("https://example.com/../did.json", options),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(
+    did_web::from_url("https://example.com/a/%2E/did.json", options),
+    Err(Error::InvalidPath)
+  );
+  assert_eq!(
+    did_web::from_url("https://example.com:0", options),
+    Err(Error::InvalidPort)
+  );
+  assert_eq!(
+    did_web::from_url("https://", options),
+    Err(Error::InvalidDomain)
+  );
+}
+
+#[test]
+fn test_round_trip() {
+  let did: DID = did!("did:web:example.com%3A3000:user:alice");
+  let url: String = did_web::to_url(&did, Options::new()).unwrap();
+
+  assert_eq!(did_web::from_url(&url, Options::new()).unwrap(), did);
+}
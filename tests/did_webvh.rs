@@ -0,0 +1,107 @@
+#![cfg(feature = "did-webvh")]
+
+use did_url::did;
+use did_url::did_web;
+use did_url::did_web::Options;
+use did_url::did_webvh;
+use did_url::did_webvh::Error;
+use did_url::did_webvh::Scid;
+use did_url::DID;
+
+const SCID: &str = "QmcVqvyp1GVB62eUc2VePaeJKLDLzSHhZYLQ2oaGVMZswi";
+
+// sha256(r#"{"versionId":"{SCID}"}"#)
+const HASH: [u8; 32] = [
+  0xd2, 0x5e, 0x88, 0x70, 0x21, 0x33, 0x4f, 0x95, 0x8f, 0xfe, 0x69, 0x9a, 0xc5, 0x0f, 0xa2, 0x93,
+  0x48, 0xb0, 0x2e, 0xce, 0xb1, 0x5a, 0x97, 0xaa, 0x2c, 0xfb, 0x9b, 0xbf, 0xb9, 0x14, 0x89, 0x6d,
+];
+
+fn webvh(data: &str) -> DID {
+  DID::parse(format!("did:webvh:{}:{}", SCID, data)).unwrap()
+}
+
+#[test]
+fn test_decode() {
+  let scid: Scid = did_webvh::decode(&webvh("example.com")).unwrap();
+
+  assert_eq!(scid.as_str(), SCID);
+  assert_eq!(scid, Scid::from_hash(&HASH));
+  assert!(scid.matches(&HASH));
+  assert!(!scid.matches(&[0; 32]));
+
+  let did: DID = DID::parse(format!("did:tdw:{}:example.com", SCID)).unwrap();
+  assert_eq!(did_webvh::decode(&did).unwrap(), scid);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_to_url() {
+  let cases: &[(&str, &str)] = &[
+    ("example.com", "https://example.com/.well-known/did.jsonl"),
+    ("example.com%3A3000", "https://example.com:3000/.well-known/did.jsonl"),
+    ("example.com:dids:issuer", "https://example.com/dids/issuer/did.jsonl"),
+    ("xn--jp-cd2fp15c.xn--fsq.jp:path", "https://xn--jp-cd2fp15c.xn--fsq.jp/path/did.jsonl"),
+  ];
+
+  for (data, url) in cases {
+    assert_eq!(did_webvh::to_url(&webvh(data), Options::new()).unwrap(), *url);
+  }
+}
+
+#[test]
+fn test_to_did_web() {
+  let did: DID = webvh("example.com%3A3000:dids:issuer")
+    .join("#key-1")
+    .unwrap();
+
+  assert_eq!(
+    did_webvh::to_did_web(&did).unwrap(),
+    "did:web:example.com%3A3000:dids:issuer#key-1"
+  );
+}
+
+#[test]
+fn test_verify() {
+  let did: DID = webvh("example.com");
+
+  assert_eq!(did_webvh::verify(&did, &HASH), Ok(()));
+  assert_eq!(
+    did_webvh::verify(&did, &HASH[1..]),
+    Err(Error::ScidMismatch)
+  );
+  assert_eq!(did_webvh::verify(&did, &[0; 32]), Err(Error::ScidMismatch));
+}
+
+#[test]
+fn test_invalid() {
+  assert_eq!(Scid::new("Qm"), Err(Error::InvalidScid));
+  assert_eq!(Scid::new("0OIl"), Err(Error::InvalidScid));
+  assert_eq!(
+    Scid::new("z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"),
+    Err(Error::InvalidScid)
+  );
+
+  assert_eq!(
+    did_webvh::decode(&did!("did:web:example.com")),
+    Err(Error::InvalidMethod)
+  );
+  assert_eq!(
+    did_webvh::decode(&did!("did:webvh:example.com")),
+    Err(Error::InvalidScid)
+  );
+
+  let did: DID = DID::parse(format!("did:webvh:{}", SCID)).unwrap();
+  assert_eq!(
+    did_webvh::decode(&did),
+    Err(Error::Web(did_web::Error::InvalidDomain))
+  );
+
+  assert_eq!(
+    did_webvh::to_url(&webvh("localhost"), Options::new().strict(true)),
+    Err(Error::Web(did_web::Error::Localhost))
+  );
+  assert_eq!(
+    did_webvh::to_url(&webvh("example.com%3A0"), Options::new()),
+    Err(Error::Web(did_web::Error::InvalidPort))
+  );
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "http")]
+
+use core::convert::TryFrom;
+use did_url::did;
+use did_url::Error;
+use did_url::RelativeDid;
+use did_url::DID;
+use http::HeaderValue;
+use http::Uri;
+
+#[test]
+fn test_did_to_header_value() {
+  let did: DID = did!("did:example:123/a?q=abc+def#key-1");
+  let value: HeaderValue = HeaderValue::from(&did);
+
+  assert_eq!(value.to_str().unwrap(), did.as_str());
+  assert_eq!(DID::try_from(&value).unwrap(), did);
+}
+
+#[test]
+fn test_header_value_to_did_invalid() {
+  let value: HeaderValue = HeaderValue::from_static("https://example.com");
+  assert_eq!(DID::try_from(&value).unwrap_err(), Error::InvalidScheme);
+
+  let value: HeaderValue = HeaderValue::from_bytes(b"did:example:\xFF").unwrap();
+  assert_eq!(DID::try_from(&value).unwrap_err(), Error::InvalidMethodId);
+}
+
+#[test]
+fn test_did_to_header_value_trimmed() {
+  let did: DID = did!("did:example:123\n");
+  let value: HeaderValue = HeaderValue::from(&did);
+
+  assert_eq!(value.to_str().unwrap(), "did:example:123");
+}
+
+#[test]
+fn test_relative_did_to_uri() {
+  let relative: RelativeDid = RelativeDid::parse("/a/b?versionId=2").unwrap();
+  let uri: Uri = Uri::try_from(&relative).unwrap();
+
+  assert_eq!(uri.path(), "/a/b");
+  assert_eq!(uri.query(), Some("versionId=2"));
+  assert_eq!(RelativeDid::try_from(&uri).unwrap(), relative);
+
+  let relative: RelativeDid = RelativeDid::parse("/a#key-1").unwrap();
+  assert_eq!(
+    Uri::try_from(&relative).unwrap_err(),
+    Error::InvalidFragment
+  );
+
+  let relative: RelativeDid = RelativeDid::parse("../a").unwrap();
+  assert_eq!(Uri::try_from(&relative).unwrap_err(), Error::InvalidPath);
+
+  let relative: RelativeDid = RelativeDid::parse("?q").unwrap();
+  assert_eq!(Uri::try_from(&relative).unwrap_err(), Error::InvalidPath);
+}
+
+#[test]
+fn test_uri_to_relative_did_invalid() {
+  let uri: Uri = Uri::from_static("https://example.com/a");
+  assert_eq!(
+    RelativeDid::try_from(&uri).unwrap_err(),
+    Error::InvalidScheme
+  );
+
+  let uri: Uri = Uri::from_static("example.com:80");
+  assert_eq!(
+    RelativeDid::try_from(&uri).unwrap_err(),
+    Error::InvalidAuthority
+  );
+
+  let uri: Uri = Uri::from_static("/a%ZZ");
+  assert_eq!(RelativeDid::try_from(&uri).unwrap_err(), Error::InvalidPath);
+}
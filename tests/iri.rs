@@ -0,0 +1,103 @@
+#![cfg(feature = "iri-string")]
+
+use core::convert::TryFrom;
+use did_url::did;
+use did_url::Error;
+use did_url::RelativeDid;
+use did_url::DID;
+use iri_string::types::IriRelativeStr;
+use iri_string::types::IriStr;
+use iri_string::types::IriString;
+use iri_string::types::UriReferenceStr;
+use iri_string::types::UriRelativeStr;
+use iri_string::types::UriStr;
+use iri_string::types::UriString;
+
+#[test]
+fn test_did_to_iri_string() {
+  let did: DID = did!("did:example:123/a?q=%2B#key-1");
+
+  let uri: &UriStr = (&did).into();
+  assert_eq!(uri.as_str(), did.as_str());
+  assert_eq!(uri.scheme_str(), "did");
+  assert_eq!(uri.fragment().map(|f| f.as_str()), Some("key-1"));
+
+  let reference: &UriReferenceStr = (&did).into();
+  assert_eq!(reference.as_str(), did.as_str());
+
+  assert_eq!(UriString::from(&did).as_str(), did.as_str());
+  assert_eq!(IriString::from(&did).as_str(), did.as_str());
+}
+
+#[test]
+fn test_iri_string_to_did() {
+  let uri: &UriStr = UriStr::new("did:example:123/a?q#key-1").unwrap();
+  assert_eq!(DID::try_from(uri).unwrap(), "did:example:123/a?q#key-1");
+
+  let iri: &IriStr = IriStr::new("did:example:123#key-1").unwrap();
+  assert_eq!(DID::try_from(iri).unwrap(), "did:example:123#key-1");
+
+  let uri: &UriStr = UriStr::new("urn:example:123").unwrap();
+  assert_eq!(DID::try_from(uri).unwrap_err(), Error::InvalidScheme);
+
+  let iri: &IriStr = IriStr::new("did:example:\u{e9}").unwrap();
+  assert_eq!(DID::try_from(iri).unwrap_err(), Error::InvalidMethodId);
+}
+
+#[test]
+fn test_relative_reference() {
+  let base: DID = did!("did:example:123/a/b");
+
+  for (reference, expected) in [
+    ("#key-1", "did:example:123/a/b#key-1"),
+    ("../c?q", "did:example:123/c?q"),
+    ("did:other:456", "did:other:456"),
+  ] {
+    let reference: &UriReferenceStr = UriReferenceStr::new(reference).unwrap();
+    assert_eq!(base.join(reference).unwrap(), expected);
+  }
+
+  let reference: &UriReferenceStr = UriReferenceStr::new("#key-1").unwrap();
+  assert_eq!(DID::try_from(reference).unwrap_err(), Error::InvalidScheme);
+
+  let reference: &UriReferenceStr = UriReferenceStr::new("did:example:123#key-1").unwrap();
+  assert_eq!(DID::try_from(reference).unwrap(), "did:example:123#key-1");
+  assert_eq!(
+    RelativeDid::try_from(reference).unwrap_err(),
+    Error::InvalidScheme
+  );
+
+  for reference in ["#key-1", "../c?q", "/a/b?versionId=2#key-1", ""] {
+    let uri: &UriReferenceStr = UriReferenceStr::new(reference).unwrap();
+    let relative: RelativeDid = RelativeDid::try_from(uri).unwrap();
+
+    assert_eq!(relative, reference);
+    assert_eq!(
+      <&UriRelativeStr>::try_from(&relative).unwrap().as_str(),
+      reference
+    );
+    assert_eq!(base.join(&relative).unwrap(), base.join(reference).unwrap());
+  }
+
+  let iri: &IriRelativeStr = IriRelativeStr::new("#cl\u{e9}").unwrap();
+  assert_eq!(
+    RelativeDid::try_from(iri).unwrap_err(),
+    Error::InvalidFragment
+  );
+
+  assert_eq!(RelativeDid::parse("k:1").unwrap_err(), Error::InvalidPath);
+  assert_eq!(
+    <&UriRelativeStr>::try_from(&RelativeDid::parse("./k:1").unwrap())
+      .unwrap()
+      .as_str(),
+    "./k:1"
+  );
+}
+
+#[test]
+fn test_did_to_iri_string_trimmed() {
+  let did: DID = did!("did:example:123\n");
+  let uri: &UriStr = (&did).into();
+
+  assert_eq!(uri.as_str(), "did:example:123");
+}
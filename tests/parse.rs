@@ -1,3 +1,5 @@
+use did_url::Error;
+use did_url::RelativeDid;
 use did_url::DID;
 
 #[test]
@@ -114,3 +116,90 @@ fn test_parse_simple_fragment() {
   assert_eq!(did.query(), None);
   assert_eq!(did.fragment(), Some("public-key-1"));
 }
+
+#[test]
+#[rustfmt::skip]
+fn test_parse_pct_encoded() {
+  let did: DID = DID::parse("did:web:example.com%3A3000/a%20b?q=%2B#key%201").unwrap();
+  assert_eq!(did.method_id(), "example.com%3A3000");
+  assert_eq!(did.path(), "/a%20b");
+  assert_eq!(did.query(), Some("q=%2B"));
+  assert_eq!(did.fragment(), Some("key%201"));
+
+  assert!(DID::parse("did:web:example.com%3").is_err());
+  assert!(DID::parse("did:web:example.com%zz").is_err());
+  assert!(DID::parse("did:example:123/%").is_err());
+  assert!(DID::parse("did:example:123?%4").is_err());
+  assert!(DID::parse("did:example:123#%g0").is_err());
+}
+
+#[test]
+fn test_parse_trimmed() {
+  let did: DID = DID::parse("\t did:method:identifier/path?q#f\r\n").unwrap();
+
+  assert_eq!(did.as_str(), "did:method:identifier/path?q#f");
+  assert_eq!(did.method(), "method");
+  assert_eq!(did.method_id(), "identifier");
+  assert_eq!(did.fragment(), Some("f"));
+  assert_eq!(did, DID::parse(did.as_str()).unwrap());
+
+  let base: DID = DID::parse("did:method:identifier/a/b").unwrap();
+  assert_eq!(base.join(" ../c#f\n").unwrap(), "did:method:identifier/c#f");
+}
+
+#[test]
+fn test_parse_relative() {
+  let relative: RelativeDid = RelativeDid::parse(" ../a/b?q=1#key-1\n").unwrap();
+
+  assert_eq!(relative.as_str(), "../a/b?q=1#key-1");
+  assert_eq!(relative.path(), "../a/b");
+  assert_eq!(relative.query(), Some("q=1"));
+  assert_eq!(relative.fragment(), Some("key-1"));
+
+  let relative: RelativeDid = RelativeDid::parse("#key-1").unwrap();
+
+  assert_eq!(relative.path(), "");
+  assert_eq!(relative.query(), None);
+  assert_eq!(relative.fragment(), Some("key-1"));
+
+  assert!(RelativeDid::parse("/a b").is_err());
+  assert!(RelativeDid::parse("#a#b").is_err());
+
+  assert_eq!(RelativeDid::parse("g:h").unwrap_err(), Error::InvalidPath);
+  assert_eq!(RelativeDid::parse("a:b/c").unwrap_err(), Error::InvalidPath);
+  assert_eq!(RelativeDid::parse("./g:h").unwrap().path(), "./g:h");
+  assert_eq!(RelativeDid::parse("/g:h").unwrap().path(), "/g:h");
+  assert_eq!(RelativeDid::parse("a/b:c").unwrap().path(), "a/b:c");
+  assert_eq!(RelativeDid::parse("?a:b").unwrap().query(), Some("a:b"));
+}
+
+#[test]
+fn test_parse_relative_join() {
+  let base: DID = DID::parse("did:example:123/a/b?q#f").unwrap();
+
+  let inputs: &[&str] = &[
+    "",
+    ".",
+    "..",
+    "../../..",
+    "g",
+    "g:h",
+    "a:b/c",
+    "./g:h",
+    "a/b:c",
+    "/g:h",
+    "//example:456/g",
+    "//example",
+    "//",
+    "///g",
+    "?a:b",
+    "#f:g",
+    "g;x=1/../y",
+  ];
+
+  for input in inputs {
+    if let Ok(relative) = RelativeDid::parse(input) {
+      assert!(base.join(relative.as_str()).is_ok(), "{}", input);
+    }
+  }
+}
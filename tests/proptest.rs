@@ -8,6 +8,13 @@ fn did_syntax() -> impl Strategy<Value = String> {
     .prop_filter("ascii", |value| value.is_ascii())
 }
 
+fn did_url_syntax() -> impl Strategy<Value = String> {
+  string_regex(
+    "did:[a-z]+:([0-9a-zA-Z._-]|%[0-9a-fA-F]{2})+(/(\\.|\\.\\.|[0-9a-zA-Z._~:@-]|%[0-9a-fA-F]{2})*)*(\\?([0-9a-zA-Z._~:@/?-]|%[0-9a-fA-F]{2})*)?(#([0-9a-zA-Z._~:@/?-]|%[0-9a-fA-F]{2})*)?",
+  )
+  .unwrap()
+}
+
 proptest! {
   #![proptest_config(ProptestConfig::with_cases(1024))]
   // TODO: Test path
@@ -17,4 +24,15 @@ proptest! {
   fn parse_did_syntax(input in did_syntax()) {
     DID::parse(&input).unwrap();
   }
+
+  #[test]
+  fn normalize_did_url_syntax(input in did_url_syntax()) {
+    let mut did: DID = DID::parse(&input).unwrap();
+    did.normalize();
+
+    let mut again: DID = DID::parse(did.as_str()).unwrap();
+    again.normalize();
+
+    prop_assert_eq!(again, did);
+  }
 }
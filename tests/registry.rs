@@ -0,0 +1,145 @@
+use did_url::Error;
+use did_url::MethodError;
+use did_url::MethodRegistry;
+use did_url::MethodSpec;
+use did_url::DID;
+
+struct Example;
+
+impl MethodSpec for Example {
+  fn name(&self) -> &str {
+    "example"
+  }
+
+  fn validate_method_id(&self, did: &DID) -> bool {
+    did.method_id().bytes().all(|byte| byte.is_ascii_digit())
+  }
+
+  fn validate_path(&self, did: &DID) -> bool {
+    did.path().is_empty()
+  }
+
+  fn validate_query(&self, did: &DID) -> bool {
+    did.query().is_none()
+  }
+
+  fn normalize(&self, did: &mut DID) {
+    let method_id: String = did.method_id().trim_start_matches('0').to_string();
+    did.set_method_id(method_id);
+  }
+}
+
+fn registry() -> MethodRegistry {
+  let mut registry: MethodRegistry = MethodRegistry::new();
+  registry.register(Example);
+  registry
+}
+
+#[test]
+fn test_parse_with() {
+  let registry: MethodRegistry = registry();
+
+  assert!(registry.contains("example"));
+  assert_eq!(registry.methods().collect::<Vec<_>>(), ["example"]);
+
+  assert_eq!(
+    DID::parse_with(&registry, "did:example:00123#key-1").unwrap(),
+    "did:example:123#key-1"
+  );
+
+  let error: MethodError = DID::parse_with(&registry, "did:example:abc").unwrap_err();
+
+  assert_eq!(error.method(), Some("example"));
+  assert_eq!(error.error(), Error::InvalidMethodId);
+  assert_eq!(error.to_string(), "Invalid Method Id (did:example)");
+
+  let error: MethodError = DID::parse_with(&registry, "did:example:123/path").unwrap_err();
+  assert_eq!(error.error(), Error::InvalidPath);
+
+  let error: MethodError = DID::parse_with(&registry, "did:example:123?query").unwrap_err();
+  assert_eq!(error.error(), Error::InvalidQuery);
+
+  let error: MethodError = DID::parse_with(&registry, "did:example").unwrap_err();
+
+  assert_eq!(error.method(), None);
+  assert_eq!(error.error(), Error::InvalidMethodId);
+  assert_eq!(error.to_string(), "Invalid Method Id");
+}
+
+#[test]
+fn test_unknown() {
+  let registry: MethodRegistry = registry();
+  assert!(DID::parse_with(&registry, "did:other:abc").is_ok());
+
+  let registry: MethodRegistry = registry.allow_unknown(false);
+  let error: MethodError = DID::parse_with(&registry, "did:other:abc").unwrap_err();
+
+  assert_eq!(error.method(), Some("other"));
+  assert_eq!(error.error(), Error::InvalidMethodName);
+  assert!(DID::parse_with(&registry, "did:example:123").is_ok());
+}
+
+#[test]
+#[cfg(all(feature = "did-key", feature = "did-plc", feature = "did-web"))]
+fn test_builtin() {
+  let registry: MethodRegistry = MethodRegistry::builtin().allow_unknown(false);
+
+  assert!(DID::parse_with(
+    &registry,
+    "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+  )
+  .is_ok());
+  assert!(DID::parse_with(&registry, "did:web:example.com:user").is_ok());
+  assert_eq!(
+    DID::parse_with(&registry, "did:plc:EWVI7NXZYOUN6ZHXRHS64OIZ").unwrap(),
+    "did:plc:ewvi7nxzyoun6zhxrhs64oiz"
+  );
+
+  let error: MethodError = DID::parse_with(&registry, "did:key:hello").unwrap_err();
+
+  assert_eq!(error.method(), Some("key"));
+  assert_eq!(error.error(), Error::InvalidMethodId);
+
+  let error: MethodError = DID::parse_with(&registry, "did:web:example.com%3A0").unwrap_err();
+  assert_eq!(error.method(), Some("web"));
+
+  let error: MethodError = DID::parse_with(&registry, "did:example:123").unwrap_err();
+  assert_eq!(error.error(), Error::InvalidMethodName);
+}
+
+#[test]
+fn test_shared() {
+  use std::sync::OnceLock;
+  use std::thread;
+
+  static REGISTRY: OnceLock<MethodRegistry> = OnceLock::new();
+
+  let registry: &'static MethodRegistry = REGISTRY.get_or_init(|| {
+    let mut registry: MethodRegistry = MethodRegistry::new().allow_unknown(false);
+    registry.register(Example);
+    registry
+  });
+
+  let handle = thread::spawn(move || DID::parse_with(registry, "did:example:0042").is_ok());
+
+  assert!(handle.join().unwrap());
+}
+
+did_url::did_method! {
+  struct Marker = "marker", |did| did.method_id().len() == 3;
+}
+
+#[test]
+fn test_register_marker() {
+  let mut registry: MethodRegistry = MethodRegistry::new().allow_unknown(false);
+  registry.register(Marker);
+
+  assert_eq!(registry.get("marker").map(MethodSpec::name), Some("marker"));
+  assert!(DID::parse_with(&registry, "did:marker:abc").is_ok());
+  assert_eq!(
+    DID::parse_with(&registry, "did:marker:abcd")
+      .unwrap_err()
+      .error(),
+    Error::InvalidMethodId
+  );
+}
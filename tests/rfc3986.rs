@@ -0,0 +1,123 @@
+//! Reference resolution examples from RFC 3986, adapted to a DID base.
+//!
+//! See https://tools.ietf.org/html/rfc3986#section-5.4
+
+use did_url::did;
+use did_url::Error;
+use did_url::DID;
+
+const BASE: &str = "did:example:123/b/c/d;p?q";
+
+#[rustfmt::skip]
+const NORMAL: &[(&str, &str)] = &[
+  ("g",       "did:example:123/b/c/g"),
+  ("./g",     "did:example:123/b/c/g"),
+  ("g/",      "did:example:123/b/c/g/"),
+  ("/g",      "did:example:123/g"),
+  ("?y",      "did:example:123/b/c/d;p?y"),
+  ("g?y",     "did:example:123/b/c/g?y"),
+  ("#s",      "did:example:123/b/c/d;p?q#s"),
+  ("g#s",     "did:example:123/b/c/g#s"),
+  ("g?y#s",   "did:example:123/b/c/g?y#s"),
+  (";x",      "did:example:123/b/c/;x"),
+  ("g;x",     "did:example:123/b/c/g;x"),
+  ("g;x?y#s", "did:example:123/b/c/g;x?y#s"),
+  ("",        "did:example:123/b/c/d;p?q"),
+  (".",       "did:example:123/b/c/"),
+  ("./",      "did:example:123/b/c/"),
+  ("..",      "did:example:123/b/"),
+  ("../",     "did:example:123/b/"),
+  ("../g",    "did:example:123/b/g"),
+  ("../..",   "did:example:123/"),
+  ("../../",  "did:example:123/"),
+  ("../../g", "did:example:123/g"),
+];
+
+#[rustfmt::skip]
+const ABNORMAL: &[(&str, &str)] = &[
+  ("../../../g",    "did:example:123/g"),
+  ("../../../../g", "did:example:123/g"),
+  ("/./g",          "did:example:123/g"),
+  ("/../g",         "did:example:123/g"),
+  ("g.",            "did:example:123/b/c/g."),
+  (".g",            "did:example:123/b/c/.g"),
+  ("g..",           "did:example:123/b/c/g.."),
+  ("..g",           "did:example:123/b/c/..g"),
+  ("./../g",        "did:example:123/b/g"),
+  ("./g/.",         "did:example:123/b/c/g/"),
+  ("g/./h",         "did:example:123/b/c/g/h"),
+  ("g/../h",        "did:example:123/b/c/h"),
+  ("g;x=1/./y",     "did:example:123/b/c/g;x=1/y"),
+  ("g;x=1/../y",    "did:example:123/b/c/y"),
+  ("g?y/./x",       "did:example:123/b/c/g?y/./x"),
+  ("g?y/../x",      "did:example:123/b/c/g?y/../x"),
+  ("g#s/./x",       "did:example:123/b/c/g#s/./x"),
+  ("g#s/../x",      "did:example:123/b/c/g#s/../x"),
+];
+
+#[rustfmt::skip]
+const EMPTY_PATH: &[(&str, &str)] = &[
+  ("g",     "did:example:123/g"),
+  ("./g",   "did:example:123/g"),
+  ("../g",  "did:example:123/g"),
+  ("g/h",   "did:example:123/g/h"),
+  (".",     "did:example:123/"),
+  ("?y",    "did:example:123?y"),
+  ("#s",    "did:example:123#s"),
+  ("",      "did:example:123"),
+];
+
+#[rustfmt::skip]
+const ABSOLUTE: &[(&str, &str)] = &[
+  ("did:other:xyz",          "did:other:xyz"),
+  ("did:other:xyz#k",        "did:other:xyz#k"),
+  ("did:example:123?y",      "did:example:123?y"),
+  ("did:other:xyz/a/../g",   "did:other:xyz/g"),
+  ("//g:h",                  "did:g:h"),
+  ("//other:xyz/./g?y#s",    "did:other:xyz/g?y#s"),
+];
+
+fn assert_table(base: &str, table: &[(&str, &str)]) {
+  let base: DID = did!(base);
+
+  for (reference, expected) in table {
+    assert_eq!(base.join(reference).unwrap(), *expected, "{:?}", reference);
+  }
+}
+
+#[test]
+fn test_normal_examples() {
+  assert_table(BASE, NORMAL);
+}
+
+#[test]
+fn test_abnormal_examples() {
+  assert_table(BASE, ABNORMAL);
+}
+
+#[test]
+fn test_empty_base_path() {
+  assert_table("did:example:123", EMPTY_PATH);
+}
+
+#[test]
+fn test_absolute_examples() {
+  assert_table(BASE, ABSOLUTE);
+}
+
+#[test]
+fn test_other_scheme() {
+  let base: DID = did!(BASE);
+
+  assert_eq!(base.join("g:h").unwrap_err(), Error::InvalidScheme);
+  assert_eq!(
+    base.join("https://example.com").unwrap_err(),
+    Error::InvalidScheme
+  );
+  assert!(base.join("//g").is_err());
+  assert!(base.join("did:other").is_err());
+
+  // A colon after the first segment is not a scheme delimiter.
+  assert_eq!(base.join("./g:h").unwrap(), "did:example:123/b/c/g:h");
+  assert_eq!(base.join("g/h:i").unwrap(), "did:example:123/b/c/g/h:i");
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "spec-registry")]
+
+use did_url::did;
+use did_url::spec_registry::Check;
+use did_url::spec_registry::Error;
+use did_url::spec_registry::MethodEntry;
+use did_url::spec_registry::SpecRegistry;
+use did_url::spec_registry::Status;
+
+const CUSTOM: &str = r#"[
+  {
+    "name": "example",
+    "status": "registered",
+    "specification": "https://example.com/spec",
+    "contactName": "Example",
+    "contactEmail": "did@example.com",
+    "contactWebsite": "",
+    "verifiableDataRegistry": "Example Ledger"
+  },
+  {
+    "name": "legacy",
+    "status": "deprecated",
+    "specification": "https://example.com/legacy"
+  }
+]"#;
+
+#[test]
+fn test_insert_json() {
+  let mut registry: SpecRegistry = SpecRegistry::new();
+
+  assert!(registry.is_empty());
+  assert_eq!(
+    registry.check(&did!("did:web:example.com")),
+    Check::Unregistered
+  );
+
+  registry
+    .insert_json(
+      r#"{
+        "name": "web",
+        "status": "registered",
+        "specification": "https://w3c-ccg.github.io/did-method-web/",
+        "contactName": "W3C Credentials Community Group",
+        "contactEmail": "",
+        "contactWebsite": "https://w3c-ccg.github.io/",
+        "verifiableDataRegistry": "Web"
+      }"#,
+    )
+    .unwrap();
+
+  registry
+    .insert_json(
+      r#"{
+        "name": "legacy",
+        "status": "deprecated",
+        "specification": "https://example.com/legacy"
+      }"#,
+    )
+    .unwrap();
+
+  assert_eq!(registry.len(), 2);
+  assert!(registry.contains("web"));
+  assert_eq!(registry.get("web").unwrap().contact().email(), None);
+  assert!(matches!(
+    registry.check(&did!("did:web:example.com")),
+    Check::Registered(entry) if entry.status() == Status::Registered
+  ));
+  assert!(matches!(
+    registry.check(&did!("did:legacy:123")),
+    Check::Deprecated(entry) if entry.status() == Status::Deprecated
+  ));
+
+  let names: Vec<&str> = registry.iter().map(MethodEntry::name).collect();
+  assert_eq!(names, ["legacy", "web"]);
+
+  assert_eq!(
+    registry.insert_json(r#"{"name": "web", "status": "registered", "specification": "x"}"#),
+    Err(Error::DuplicateMethod)
+  );
+  assert_eq!(registry.insert_json("[]"), Err(Error::InvalidEntry));
+  assert_eq!(registry.insert_json("{"), Err(Error::InvalidJson));
+}
+
+#[test]
+fn test_from_json() {
+  let registry: SpecRegistry = SpecRegistry::from_json(CUSTOM).unwrap();
+
+  assert_eq!(registry.len(), 2);
+
+  let entry: &MethodEntry = registry.get("example").unwrap();
+
+  assert_eq!(entry.status(), Status::Registered);
+  assert_eq!(entry.specification(), "https://example.com/spec");
+  assert_eq!(entry.contact().name(), Some("Example"));
+  assert_eq!(entry.contact().email(), Some("did@example.com"));
+  assert_eq!(entry.contact().website(), None);
+  assert_eq!(entry.verifiable_data_registry(), Some("Example Ledger"));
+
+  let entry: &MethodEntry = registry.get("legacy").unwrap();
+
+  assert_eq!(entry.status(), Status::Deprecated);
+  assert_eq!(entry.contact().name(), None);
+  assert_eq!(entry.verifiable_data_registry(), None);
+
+  assert_eq!(
+    SpecRegistry::from_slice(CUSTOM.as_bytes()).unwrap(),
+    registry
+  );
+}
+
+#[test]
+fn test_check() {
+  let registry: SpecRegistry = SpecRegistry::from_json(CUSTOM).unwrap();
+
+  assert!(matches!(
+    registry.check(&did!("did:example:123")),
+    Check::Registered(entry) if entry.name() == "example"
+  ));
+  assert!(matches!(
+    registry.check(&did!("did:legacy:123")),
+    Check::Deprecated(entry) if entry.name() == "legacy"
+  ));
+  assert_eq!(
+    registry.check(&did!("did:unknown:123")),
+    Check::Unregistered
+  );
+  assert_eq!(registry.check_method("unknown"), Check::Unregistered);
+}
+
+#[test]
+fn test_invalid() {
+  assert_eq!(
+    SpecRegistry::from_json("[").unwrap_err(),
+    Error::InvalidJson
+  );
+  assert_eq!(
+    SpecRegistry::from_json("{}").unwrap_err(),
+    Error::InvalidEntry
+  );
+  assert_eq!(
+    SpecRegistry::from_json("[1]").unwrap_err(),
+    Error::InvalidEntry
+  );
+  assert_eq!(
+    SpecRegistry::from_json(r#"[{"name": "example", "status": "registered"}]"#).unwrap_err(),
+    Error::InvalidEntry
+  );
+  assert_eq!(
+    SpecRegistry::from_json(
+      r#"[{"name": "Example", "status": "registered", "specification": "x"}]"#
+    )
+    .unwrap_err(),
+    Error::InvalidMethodName
+  );
+  assert_eq!(
+    SpecRegistry::from_json(
+      r#"[{"name": "example", "status": "withdrawn", "specification": "x"}]"#
+    )
+    .unwrap_err(),
+    Error::InvalidStatus
+  );
+  assert_eq!(
+    SpecRegistry::from_json(
+      r#"[
+        {"name": "example", "status": "registered", "specification": "x"},
+        {"name": "example", "status": "deprecated", "specification": "y"}
+      ]"#
+    )
+    .unwrap_err(),
+    Error::DuplicateMethod
+  );
+}
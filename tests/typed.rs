@@ -0,0 +1,349 @@
+use core::convert::TryFrom;
+use did_url::did;
+use did_url::did_method;
+use did_url::DidMethod;
+use did_url::Error;
+use did_url::TypedDid;
+use did_url::DID;
+
+did_method! {
+  /// A method with numeric identifiers.
+  pub struct Example = "example", |did| did.method_id().bytes().all(|byte| byte.is_ascii_digit());
+}
+
+did_method! {
+  struct Any = "any";
+}
+
+fn accepts_example(did: &TypedDid<Example>) -> &str {
+  did.method_id()
+}
+
+#[test]
+fn test_parse() {
+  let did: TypedDid<Example> = TypedDid::parse("did:example:123/path#key-1").unwrap();
+
+  assert_eq!(Example::NAME, "example");
+  assert_eq!(did.method(), Example::NAME);
+  assert_eq!(did.fragment(), Some("key-1"));
+  assert_eq!(accepts_example(&did), "123");
+  assert_eq!(did, "did:example:123/path#key-1");
+  assert_eq!(did.to_string(), "did:example:123/path#key-1");
+
+  assert!(TypedDid::<Any>::parse("did:any:abc").is_ok());
+}
+
+#[test]
+fn test_parse_invalid() {
+  assert_eq!(
+    TypedDid::<Example>::parse("did:other:123").unwrap_err(),
+    Error::InvalidMethodName
+  );
+  assert_eq!(
+    TypedDid::<Example>::parse("did:example:abc").unwrap_err(),
+    Error::InvalidMethodId
+  );
+  assert_eq!(
+    TypedDid::<Example>::parse("example:123").unwrap_err(),
+    Error::InvalidScheme
+  );
+}
+
+#[test]
+fn test_conversions() {
+  let untyped: DID = did!("did:example:123");
+  let typed: TypedDid<Example> = TypedDid::try_from(untyped.clone()).unwrap();
+
+  assert_eq!(typed, untyped);
+  assert_eq!(typed.as_did(), &untyped);
+  assert_eq!(
+    "did:example:123".parse::<TypedDid<Example>>().unwrap(),
+    typed
+  );
+  assert_eq!(
+    TypedDid::<Example>::try_from(String::from("did:example:123")).unwrap(),
+    typed
+  );
+  assert_eq!(DID::from(typed.clone()), untyped);
+  assert_eq!(String::from(typed.clone()), "did:example:123");
+  assert_eq!(typed.into_did(), untyped);
+
+  assert!(TypedDid::<Any>::try_from(untyped).is_err());
+}
+
+#[cfg(feature = "did-web")]
+#[test]
+fn test_did_web() {
+  use did_url::did_web::DidWeb;
+  use did_url::did_web::Options;
+
+  let did: TypedDid<DidWeb> = TypedDid::parse("did:web:example.com:user").unwrap();
+
+  assert_eq!(
+    did.to_url(Options::new()).unwrap(),
+    "https://example.com/user/did.json"
+  );
+  assert_eq!(
+    TypedDid::<DidWeb>::parse("did:web:exa_mple.com").unwrap_err(),
+    Error::InvalidMethodId
+  );
+  assert_eq!(
+    TypedDid::<DidWeb>::parse("did:key:z6Mk").unwrap_err(),
+    Error::InvalidMethodName
+  );
+}
+
+#[cfg(feature = "did-plc")]
+#[test]
+fn test_did_plc() {
+  use did_url::did_plc::DidPlc;
+
+  let did: TypedDid<DidPlc> = TypedDid::parse("did:plc:EWVI7NXZYOUN6ZHXRHS64OIZ").unwrap();
+
+  assert_eq!(did.decode().as_str(), "ewvi7nxzyoun6zhxrhs64oiz");
+}
+
+#[cfg(feature = "did-indy")]
+#[test]
+fn test_did_sov() {
+  use did_url::did_indy::DidIndy;
+  use did_url::did_indy::DidSov;
+
+  let did: TypedDid<DidSov> = TypedDid::parse("did:sov:WRfXPg8dantKVubE3HX8pw").unwrap();
+
+  assert_eq!(did.decode().as_str(), "WRfXPg8dantKVubE3HX8pw");
+  assert!(TypedDid::<DidIndy>::parse(did.as_str()).is_err());
+}
+
+/// Parses each input as a `TypedDid<M>` and checks that every accepted DID is
+/// also accepted by `decode`, the invariant the typed `decode` accessors rely
+/// on to never panic.
+#[allow(dead_code)]
+fn accepted<M, T, E>(inputs: &[&str], decode: impl Fn(&DID) -> Result<T, E>) -> Vec<TypedDid<M>>
+where
+  M: DidMethod,
+{
+  let mut output: Vec<TypedDid<M>> = Vec::new();
+
+  for input in inputs {
+    if let Ok(did) = TypedDid::<M>::parse(input) {
+      assert!(decode(did.as_did()).is_ok(), "{}", input);
+      output.push(did);
+    }
+  }
+
+  output
+}
+
+#[cfg(feature = "did-dht")]
+#[test]
+fn test_decode_dht() {
+  use did_url::did_dht;
+  use did_url::did_dht::DidDht;
+
+  let dids: Vec<TypedDid<DidDht>> = accepted(
+    &[
+      "did:dht:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6y",
+      "did:dht:i9xkp8ddcbcg8jwq54ox699wuzxyifsqx4jru45zodqu453ksz6",
+    ],
+    did_dht::decode,
+  );
+
+  assert_eq!(dids.len(), 1);
+  assert_eq!(dids[0].decode(), did_dht::decode(dids[0].as_did()).unwrap());
+}
+
+#[cfg(feature = "did-ethr")]
+#[test]
+fn test_decode_ethr() {
+  use did_url::did_ethr;
+  use did_url::did_ethr::DidEthr;
+
+  let dids: Vec<TypedDid<DidEthr>> = accepted(
+    &[
+      "did:ethr:0xb9c5714089478a327f09197987f16f9e5d936e8a",
+      "did:ethr:goerli:0xb9c5714089478a327f09197987f16f9e5d936e8a",
+      "did:ethr:0xb9c5714089478a327f09197987f16f9e5d936e8",
+    ],
+    did_ethr::decode,
+  );
+
+  assert_eq!(dids.len(), 2);
+
+  for did in dids {
+    assert_eq!(did.decode(), did_ethr::decode(did.as_did()).unwrap());
+  }
+}
+
+#[cfg(feature = "did-indy")]
+#[test]
+fn test_decode_indy() {
+  use did_url::did_indy;
+  use did_url::did_indy::DidIndy;
+  use did_url::did_indy::DidSov;
+
+  let dids: Vec<TypedDid<DidIndy>> = accepted(
+    &[
+      "did:indy:sovrin:WRfXPg8dantKVubE3HX8pw",
+      "did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw",
+      "did:indy:WRfXPg8dantKVubE3HX8pw",
+    ],
+    did_indy::decode,
+  );
+
+  assert_eq!(dids.len(), 2);
+
+  for did in dids {
+    assert_eq!(did.decode(), did_indy::decode(did.as_did()).unwrap());
+  }
+
+  let dids: Vec<TypedDid<DidSov>> = accepted(
+    &["did:sov:WRfXPg8dantKVubE3HX8pw", "did:sov:0OIl"],
+    did_indy::decode_sov,
+  );
+
+  assert_eq!(dids.len(), 1);
+  assert_eq!(
+    dids[0].decode(),
+    did_indy::decode_sov(dids[0].as_did()).unwrap()
+  );
+}
+
+#[cfg(feature = "did-ion")]
+#[test]
+fn test_decode_ion() {
+  use did_url::did_ion;
+  use did_url::did_ion::DidIon;
+
+  let dids: Vec<TypedDid<DidIon>> = accepted(
+    &[
+      "did:ion:EiDqDRTHY4QAs3hw95H9_XNkjimWpHT6BJhaaVXYMJDCfQ",
+      "did:ion:EiDqDRTHY4QAs3hw95H9",
+    ],
+    did_ion::decode,
+  );
+
+  assert_eq!(dids.len(), 1);
+  assert_eq!(dids[0].decode(), did_ion::decode(dids[0].as_did()).unwrap());
+}
+
+#[cfg(feature = "did-jwk")]
+#[test]
+fn test_decode_jwk() {
+  use did_url::did_jwk;
+  use did_url::did_jwk::DidJwk;
+
+  let dids: Vec<TypedDid<DidJwk>> = accepted(
+    &[
+      "did:jwk:eyJjcnYiOiJQLTI1NiIsImt0eSI6IkVDIiwieCI6ImFjYklRaXVNczNpOF91c3pFakoydHBUdFJNNEVVM3l6OTFQSDZDZEgyVjAiLCJ5IjoiX0tjeUxqOXZXTXB0bm1LdG00NkdxRHo4d2Y3NEk1TEtncmwyR3pIM25TRSJ9",
+      "did:jwk:eyJrdHkiOiJPS1AiLCJjcnYiOiJFZDI1NTE5IiwieCI6IkFBIiwiZCI6IkFBIn0",
+    ],
+    did_jwk::decode,
+  );
+
+  assert_eq!(dids.len(), 1);
+  assert_eq!(dids[0].decode(), did_jwk::decode(dids[0].as_did()).unwrap());
+}
+
+#[cfg(feature = "did-key")]
+#[test]
+fn test_decode_key() {
+  use did_url::did_key;
+  use did_url::did_key::DidKey;
+
+  let dids: Vec<TypedDid<DidKey>> = accepted(
+    &[
+      "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+      "did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F",
+      "did:key:hello",
+    ],
+    did_key::decode,
+  );
+
+  assert_eq!(dids.len(), 2);
+
+  for did in dids {
+    assert_eq!(did.decode(), did_key::decode(did.as_did()).unwrap());
+  }
+}
+
+#[cfg(feature = "did-peer")]
+#[test]
+fn test_decode_peer() {
+  use did_url::did_peer;
+  use did_url::did_peer::DidPeer;
+
+  let dids: Vec<TypedDid<DidPeer>> = accepted(
+    &[
+      "did:peer:0z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+      "did:peer:9z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+    ],
+    did_peer::decode,
+  );
+
+  assert_eq!(dids.len(), 1);
+  assert_eq!(
+    dids[0].decode(),
+    did_peer::decode(dids[0].as_did()).unwrap()
+  );
+}
+
+#[cfg(feature = "did-pkh")]
+#[test]
+fn test_decode_pkh() {
+  use did_url::did_pkh;
+  use did_url::did_pkh::DidPkh;
+
+  let dids: Vec<TypedDid<DidPkh>> = accepted(
+    &[
+      "did:pkh:eip155:1:0xb9c5714089478a327f09197987f16f9e5d936e8a",
+      "did:pkh:eip155:0xb9c5714089478a327f09197987f16f9e5d936e8a",
+    ],
+    did_pkh::decode,
+  );
+
+  assert_eq!(dids.len(), 1);
+  assert_eq!(dids[0].decode(), did_pkh::decode(dids[0].as_did()).unwrap());
+}
+
+#[cfg(feature = "did-plc")]
+#[test]
+fn test_decode_plc() {
+  use did_url::did_plc;
+  use did_url::did_plc::DidPlc;
+  use did_url::did_plc::Options;
+
+  let dids: Vec<TypedDid<DidPlc>> = accepted(
+    &[
+      "did:plc:EWVI7NXZYOUN6ZHXRHS64OIZ",
+      "did:plc:ewvi7nxzyoun6zhxrhs64oi",
+    ],
+    |did| did_plc::decode(did, Options::new()),
+  );
+
+  assert_eq!(dids.len(), 1);
+  assert_eq!(dids[0], "did:plc:ewvi7nxzyoun6zhxrhs64oiz");
+  assert_eq!(dids[0].decode().as_str(), "ewvi7nxzyoun6zhxrhs64oiz");
+}
+
+#[cfg(feature = "did-webvh")]
+#[test]
+fn test_decode_webvh() {
+  use did_url::did_webvh;
+  use did_url::did_webvh::DidWebVh;
+
+  let dids: Vec<TypedDid<DidWebVh>> = accepted(
+    &[
+      "did:webvh:QmcVqvyp1GVB62eUc2VePaeJKLDLzSHhZYLQ2oaGVMZswi:example.com",
+      "did:webvh:QmcVqvyp1GVB62eUc2VePaeJKLDLzSHhZYLQ2oaGVMZswi:exa_mple.com",
+      "did:webvh:example.com",
+    ],
+    did_webvh::decode,
+  );
+
+  assert_eq!(dids.len(), 1);
+  assert_eq!(
+    dids[0].decode(),
+    did_webvh::decode(dids[0].as_did()).unwrap()
+  );
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "url")]
+
+use core::convert::TryFrom;
+use did_url::did;
+use did_url::Error;
+use did_url::DID;
+use url::Url;
+
+#[test]
+fn test_did_to_url() {
+  let did: DID = did!("did:example:123/a/b;c=d?q=abc+def&e=%2F#key-1");
+  let url: Url = Url::from(&did);
+
+  assert_eq!(url.scheme(), "did");
+  assert_eq!(url.path(), "example:123/a/b;c=d");
+  assert_eq!(url.query(), Some("q=abc+def&e=%2F"));
+  assert_eq!(url.fragment(), Some("key-1"));
+  assert_eq!(url.as_str(), did.as_str());
+}
+
+#[test]
+fn test_did_to_url_trimmed() {
+  let did: DID = did!(" did:example:123#key-1\n");
+  let url: Url = Url::from(&did);
+
+  assert_eq!(did.as_str(), "did:example:123#key-1");
+  assert_eq!(url.as_str(), did.as_str());
+}
+
+#[test]
+fn test_url_to_did() {
+  let url: Url = Url::parse("did:example:123/path?versionId=2#key-1").unwrap();
+  let did: DID = DID::try_from(&url).unwrap();
+
+  assert_eq!(did.method(), "example");
+  assert_eq!(did.method_id(), "123");
+  assert_eq!(did.path(), "/path");
+  assert_eq!(did.query(), Some("versionId=2"));
+  assert_eq!(did.fragment(), Some("key-1"));
+  assert_eq!(Url::from(did), url);
+}
+
+#[test]
+fn test_url_to_did_invalid() {
+  let url: Url = Url::parse("https://example.com/did:example:123").unwrap();
+  assert_eq!(DID::try_from(&url).unwrap_err(), Error::InvalidScheme);
+
+  let url: Url = Url::parse("did:Example:123").unwrap();
+  assert_eq!(DID::try_from(&url).unwrap_err(), Error::InvalidMethodName);
+}